@@ -0,0 +1,71 @@
+// access.rs
+use futures::future::try_join_all;
+use futures::try_join;
+use kube::Api;
+use kube::api::PostParams;
+use k8s_openapi::api::authorization::v1::{ResourceAttributes, SelfSubjectAccessReview, SelfSubjectAccessReviewSpec};
+
+use crate::api::context::KubeContext;
+use crate::models::access::Permissions;
+use crate::models::error::AppError;
+
+async fn can_list(ctx: &KubeContext, group: &str, resource: &str, namespace: Option<&str>) -> Result<bool, AppError> {
+    let api: Api<SelfSubjectAccessReview> = Api::all(ctx.client.clone());
+    let review = SelfSubjectAccessReview {
+        spec: SelfSubjectAccessReviewSpec {
+            resource_attributes: Some(ResourceAttributes {
+                group: Some(group.to_string()),
+                resource: Some(resource.to_string()),
+                verb: Some("list".to_string()),
+                namespace: namespace.map(|ns| ns.to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let post_params = PostParams::default();
+    let response = ctx.request(|| api.create(&post_params, &review)).await?;
+    Ok(response.status.map(|s| s.allowed).unwrap_or(false))
+}
+
+// namespaced 리소스는 scope의 모든 namespace에서 list 가능해야 허용
+async fn can_list_scoped(ctx: &KubeContext, group: &str, resource: &str) -> Result<bool, AppError> {
+    if ctx.scope.is_cluster_wide() {
+        return can_list(ctx, group, resource, None).await;
+    }
+    let allowed = try_join_all(
+        ctx.scope
+            .namespaces
+            .iter()
+            .map(|namespace| can_list(ctx, group, resource, Some(namespace))),
+    )
+    .await?;
+    Ok(allowed.into_iter().all(|allowed| allowed))
+}
+
+// UI 시작 후 백그라운드에서 실행: 모든 리뷰를 동시에, 재시도 없이 한 번만 요청
+pub async fn probe_permissions(ctx: &KubeContext) -> Result<Permissions, AppError> {
+    let mut ctx = ctx.for_fetch();
+    ctx.request.retries = 0;
+    let ctx = &ctx;
+
+    let (nodes, pods, deployments, statefulsets, replicasets, hpas, pvcs) = try_join!(
+        can_list(ctx, "", "nodes", None),
+        can_list_scoped(ctx, "", "pods"),
+        can_list_scoped(ctx, "apps", "deployments"),
+        can_list_scoped(ctx, "apps", "statefulsets"),
+        can_list_scoped(ctx, "apps", "replicasets"),
+        can_list_scoped(ctx, "autoscaling", "horizontalpodautoscalers"),
+        can_list_scoped(ctx, "", "persistentvolumeclaims"),
+    )?;
+
+    Ok(Permissions {
+        nodes,
+        pods,
+        workloads: deployments && statefulsets && replicasets,
+        hpas,
+        pvcs,
+    })
+}
@@ -0,0 +1,110 @@
+// context.rs
+use kube::config::KubeConfigOptions;
+use kube::{Client, Config};
+use std::future::Future;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::models::config::{RequestConfig, ScopeConfig};
+use crate::models::error::AppError;
+use crate::models::progress::FetchProgress;
+
+const BASE_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(8);
+
+// API 조회에 필요한 client, 조회 범위, timeout/retry 설정
+#[derive(Clone)]
+pub struct KubeContext {
+    pub client: Client,
+    pub scope: ScopeConfig,
+    pub request: RequestConfig,
+    pub progress: Arc<FetchProgress>,
+    retry_count: Arc<AtomicU32>,
+}
+
+// 일시적인 장애(네트워크, 429, 5xx)만 재시도
+fn is_transient(err: &kube::Error) -> bool {
+    match err {
+        kube::Error::Api(response) => response.code == 429 || response.code >= 500,
+        kube::Error::HyperError(_) | kube::Error::Service(_) => true,
+        _ => false,
+    }
+}
+
+fn backoff_delay(attempt: u32) -> Duration {
+    BASE_BACKOFF
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(MAX_BACKOFF)
+}
+
+impl KubeContext {
+    pub async fn new(scope: ScopeConfig, request: RequestConfig) -> Result<Self, AppError> {
+        let config = Config::infer().await.map_err(|e| AppError::KubeError(e.to_string()))?;
+        Self::from_config(config, scope, request)
+    }
+
+    // kubeconfig의 특정 context로 연결 (diff에서 두 클러스터 비교)
+    pub async fn for_context(name: &str, scope: ScopeConfig, request: RequestConfig) -> Result<Self, AppError> {
+        let options = KubeConfigOptions { context: Some(name.to_string()), ..Default::default() };
+        let config = Config::from_kubeconfig(&options)
+            .await
+            .map_err(|e| AppError::KubeError(format!("context '{}': {}", name, e)))?;
+        Self::from_config(config, scope, request)
+    }
+
+    fn from_config(mut config: Config, scope: ScopeConfig, request: RequestConfig) -> Result<Self, AppError> {
+        config.connect_timeout = Some(request.timeout);
+        config.read_timeout = Some(request.timeout);
+        let client = Client::try_from(config).map_err(|e| AppError::KubeError(e.to_string()))?;
+
+        Ok(Self {
+            client,
+            scope,
+            request,
+            progress: Arc::new(FetchProgress::new()),
+            retry_count: Arc::new(AtomicU32::new(0)),
+        })
+    }
+
+    // client는 공유하고 재시도 횟수와 진행 상황만 새로 집계하는 context (조회 1회 단위)
+    pub fn for_fetch(&self) -> Self {
+        Self {
+            progress: Arc::new(FetchProgress::new()),
+            retry_count: Arc::new(AtomicU32::new(0)),
+            ..self.clone()
+        }
+    }
+
+    // 이 context로 수행한 요청 중 재시도한 횟수
+    pub fn retry_count(&self) -> u32 {
+        self.retry_count.load(Ordering::Relaxed)
+    }
+
+    // 요청마다 timeout을 적용하고, 일시적인 에러는 exponential backoff로 재시도
+    pub async fn request<T, F, Fut>(&self, f: F) -> Result<T, AppError>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, kube::Error>>,
+    {
+        let mut attempt = 0;
+        loop {
+            let error = match tokio::time::timeout(self.request.timeout, f()).await {
+                Ok(Ok(value)) => return Ok(value),
+                Ok(Err(e)) if is_transient(&e) => AppError::KubeError(e.to_string()),
+                Ok(Err(e)) => return Err(AppError::KubeError(e.to_string())),
+                Err(_) => AppError::KubeError(format!(
+                    "Request timed out after {}s",
+                    self.request.timeout.as_secs()
+                )),
+            };
+
+            if attempt >= self.request.retries {
+                return Err(error);
+            }
+            tokio::time::sleep(backoff_delay(attempt)).await;
+            attempt += 1;
+            self.retry_count.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
@@ -0,0 +1,129 @@
+// dataset.rs
+use crate::api::context::KubeContext;
+use crate::api::hpa::{fetch_hpa_data, hpa_table, HpaData, HPA_COLUMNS};
+use crate::api::list::list_cluster;
+use crate::api::namespace::{build_namespace_rows, fetch_namespace_rows, namespace_table, NamespaceRow, NAMESPACE_COLUMNS};
+use crate::api::node::{build_node_data, fetch_node_data, node_table, NodeData, NODE_COLUMNS};
+use crate::api::pod::{fetch_pod_rows, pod_table, PodRow, RequestTotals, POD_COLUMNS};
+use crate::api::priority::{fetch_priority_bands, priority_table, PriorityBand, PRIORITY_COLUMNS};
+use crate::api::storage::{
+    fetch_claims, pvc_table, storage_class_table, storage_table, ClaimRow, PVC_COLUMNS, STORAGE_CLASS_COLUMNS,
+    STORAGE_COLUMNS,
+};
+use crate::models::app::ViewMode;
+use crate::models::config::{SearchConfig, SortConfig};
+use crate::models::error::AppError;
+use crate::models::table::{ColumnDef, Table};
+use crate::models::warning::ParseWarning;
+use k8s_openapi::api::core::v1::Node;
+
+// 마지막으로 조회한 뷰별 데이터
+// 정렬/필터는 API 재조회 없이 이 데이터로 다시 계산
+pub enum Dataset {
+    Node(NodeData),
+    Pod(Vec<PodRow>),
+    Namespace(Vec<NamespaceRow>),
+    Priority(Vec<PriorityBand>),
+    Hpa(HpaData),
+    Storage(Vec<ClaimRow>),
+    StorageClass(Vec<ClaimRow>),
+    Pvc(Vec<ClaimRow>),
+}
+
+// 뷰에 필요한 데이터를 조회하고, 파싱할 수 없었던 quantity 값을 함께 반환
+pub async fn fetch_dataset(ctx: &KubeContext, view_mode: ViewMode) -> Result<(Dataset, Vec<ParseWarning>), AppError> {
+    let mut warnings = Vec::new();
+    let dataset = match view_mode {
+        ViewMode::Node => Dataset::Node(fetch_node_data(ctx, &mut warnings).await?),
+        ViewMode::Pod => Dataset::Pod(fetch_pod_rows(ctx, &mut warnings).await?),
+        ViewMode::Namespace => Dataset::Namespace(fetch_namespace_rows(ctx, &mut warnings).await?),
+        ViewMode::Priority => Dataset::Priority(fetch_priority_bands(ctx, &mut warnings).await?),
+        ViewMode::Hpa => Dataset::Hpa(fetch_hpa_data(ctx, &mut warnings).await?),
+        ViewMode::Storage => Dataset::Storage(fetch_claims(ctx, &mut warnings).await?),
+        ViewMode::StorageClass => Dataset::StorageClass(fetch_claims(ctx, &mut warnings).await?),
+        ViewMode::Pvc => Dataset::Pvc(fetch_claims(ctx, &mut warnings).await?),
+    };
+    Ok((dataset, warnings))
+}
+
+// Node/Namespace 뷰와 같은 합계(RequestTotals)를 Pod 목록 한 번 조회로 계산 (report, serve, check, --record)
+#[derive(Clone)]
+pub struct ClusterUsage {
+    pub nodes: NodeData,
+    pub namespaces: Vec<NamespaceRow>,
+    pub pods: Vec<PodRow>,
+}
+
+pub async fn fetch_cluster_usage(ctx: &KubeContext) -> Result<(ClusterUsage, Vec<ParseWarning>), AppError> {
+    let mut warnings = Vec::new();
+    let node_list: Option<Vec<Node>> = list_cluster(ctx).await?;
+    let pods = fetch_pod_rows(ctx, &mut warnings).await?;
+
+    let mut totals = RequestTotals::default();
+    for pod in &pods {
+        totals.add(pod);
+    }
+
+    let usage = ClusterUsage {
+        nodes: build_node_data(node_list, &totals.by_node, &mut warnings),
+        namespaces: build_namespace_rows(ctx, &totals.by_namespace).await?,
+        pods,
+    };
+    Ok((usage, warnings))
+}
+
+// 뷰의 컬럼 헤더 (조회 전에 --sort/--filter 컬럼 확인용)
+pub fn column_headers(view_mode: ViewMode) -> Vec<&'static str> {
+    fn headers<R>(columns: &[ColumnDef<R>]) -> Vec<&'static str> {
+        columns.iter().map(|column| column.header).collect()
+    }
+
+    match view_mode {
+        ViewMode::Node => headers(NODE_COLUMNS),
+        ViewMode::Pod => headers(POD_COLUMNS),
+        ViewMode::Namespace => headers(NAMESPACE_COLUMNS),
+        ViewMode::Priority => headers(PRIORITY_COLUMNS),
+        ViewMode::Hpa => headers(HPA_COLUMNS),
+        ViewMode::Storage => headers(STORAGE_COLUMNS),
+        ViewMode::StorageClass => headers(STORAGE_CLASS_COLUMNS),
+        ViewMode::Pvc => headers(PVC_COLUMNS),
+    }
+}
+
+impl Dataset {
+    pub fn view_mode(&self) -> ViewMode {
+        match self {
+            Dataset::Node(_) => ViewMode::Node,
+            Dataset::Pod(_) => ViewMode::Pod,
+            Dataset::Namespace(_) => ViewMode::Namespace,
+            Dataset::Priority(_) => ViewMode::Priority,
+            Dataset::Hpa(_) => ViewMode::Hpa,
+            Dataset::Storage(_) => ViewMode::Storage,
+            Dataset::StorageClass(_) => ViewMode::StorageClass,
+            Dataset::Pvc(_) => ViewMode::Pvc,
+        }
+    }
+
+    // 뷰의 컬럼 정의에 따라 필터/정렬/합계를 적용한 테이블
+    pub fn to_table(&self, search_config: SearchConfig, sort_config: SortConfig) -> Table {
+        match self {
+            Dataset::Node(data) => node_table(data, Some(search_config), Some(sort_config)),
+            Dataset::Pod(rows) => pod_table(rows, Some(search_config), Some(sort_config)),
+            Dataset::Namespace(rows) => namespace_table(rows, Some(search_config), Some(sort_config)),
+            Dataset::Priority(bands) => priority_table(bands, Some(search_config), Some(sort_config)),
+            Dataset::Hpa(data) => hpa_table(data, Some(search_config), Some(sort_config)),
+            Dataset::Storage(claims) => storage_table(claims, Some(search_config), Some(sort_config)),
+            Dataset::StorageClass(claims) => storage_class_table(claims, Some(search_config), Some(sort_config)),
+            Dataset::Pvc(claims) => pvc_table(claims, Some(search_config), Some(sort_config)),
+        }
+    }
+
+    // 테이블 제목에 표시할 요약
+    pub fn summary(&self) -> Option<String> {
+        match self {
+            Dataset::Node(data) => data.summary(),
+            Dataset::Hpa(data) => Some(data.summary()),
+            _ => None,
+        }
+    }
+}
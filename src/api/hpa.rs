@@ -0,0 +1,260 @@
+// hpa.rs
+use k8s_openapi::api::apps::v1::{Deployment, ReplicaSet, StatefulSet};
+use k8s_openapi::api::autoscaling::v2::HorizontalPodAutoscaler;
+use k8s_openapi::api::core::v1::{Node, Pod};
+use std::collections::HashMap;
+
+use crate::api::context::KubeContext;
+use crate::api::list::{for_each_namespaced, list_cluster, list_namespaced};
+use crate::api::pod::counts_toward_requests;
+use crate::util::common::{extract_quantity, format_cpu, format_memory, parse_cpu, parse_memory, percent};
+use crate::models::error::AppError;
+use crate::models::config::{SearchConfig, SortConfig};
+use crate::models::table::{CellValue, ColumnDef, Table};
+use crate::models::warning::{ParseWarning, WarningSource};
+use crate::models::resource::{Cpu, Memory, Resources};
+
+// (namespace, kind, name) -> replica 1개당 리소스
+type TemplateKey = (String, String, String);
+
+async fn collect_template_resources(
+    ctx: &KubeContext,
+    warnings: &mut Vec<ParseWarning>,
+) -> Result<HashMap<TemplateKey, Resources>, AppError> {
+    let mut templates = HashMap::new();
+
+    let deployment_list: Vec<Deployment> = list_namespaced(ctx).await?;
+    for deployment in deployment_list {
+        let source = WarningSource::new("Deployment", &deployment.metadata);
+        let key = (
+            deployment.metadata.namespace.unwrap_or_default(),
+            "Deployment".to_string(),
+            deployment.metadata.name.unwrap_or_default(),
+        );
+        let mut resources = Resources::new();
+        if let Some(spec) = deployment.spec.as_ref().and_then(|s| s.template.spec.as_ref()) {
+            resources.add_pod_spec(spec, &source, warnings);
+        }
+        templates.insert(key, resources);
+    }
+
+    let stateful_set_list: Vec<StatefulSet> = list_namespaced(ctx).await?;
+    for stateful_set in stateful_set_list {
+        let source = WarningSource::new("StatefulSet", &stateful_set.metadata);
+        let key = (
+            stateful_set.metadata.namespace.unwrap_or_default(),
+            "StatefulSet".to_string(),
+            stateful_set.metadata.name.unwrap_or_default(),
+        );
+        let mut resources = Resources::new();
+        if let Some(spec) = stateful_set.spec.as_ref().and_then(|s| s.template.spec.as_ref()) {
+            resources.add_pod_spec(spec, &source, warnings);
+        }
+        templates.insert(key, resources);
+    }
+
+    let replica_set_list: Vec<ReplicaSet> = list_namespaced(ctx).await?;
+    for replica_set in replica_set_list {
+        let source = WarningSource::new("ReplicaSet", &replica_set.metadata);
+        let key = (
+            replica_set.metadata.namespace.unwrap_or_default(),
+            "ReplicaSet".to_string(),
+            replica_set.metadata.name.unwrap_or_default(),
+        );
+        let mut resources = Resources::new();
+        if let Some(spec) = replica_set.spec.as_ref()
+            .and_then(|s| s.template.as_ref())
+            .and_then(|t| t.spec.as_ref())
+        {
+            resources.add_pod_spec(spec, &source, warnings);
+        }
+        templates.insert(key, resources);
+    }
+
+    Ok(templates)
+}
+
+// maxReplicas 예측은 i64::MAX까지 saturate될 수 있으므로 f64로 계산 (정수 곱셈은 overflow)
+fn format_ratio(value: i64, total: i64) -> String {
+    match percent(value, total) {
+        Some(ratio) => format!("{:.0}%", ratio.floor()),
+        None => "-".to_string(),
+    }
+}
+
+pub struct HpaRow {
+    pub namespace: String,
+    pub name: String,
+    pub target: String,
+    pub current_replicas: i32,
+    pub desired_replicas: i32,
+    pub min_replicas: i32,
+    pub max_replicas: i32,
+    pub current: Resources,   // 현재 replica 수 기준 request
+    pub projected: Resources, // maxReplicas 기준 request
+}
+
+pub struct HpaData {
+    pub rows: Vec<HpaRow>,
+    pub cluster: Resources, // 현재 클러스터 전체 request
+    pub allocatable: Option<(Cpu, Memory)>, // (CPU, Memory), 노드 조회 권한이 없으면 None
+}
+
+pub async fn fetch_hpa_data(ctx: &KubeContext, warnings: &mut Vec<ParseWarning>) -> Result<HpaData, AppError> {
+    let hpa_list: Vec<HorizontalPodAutoscaler> = list_namespaced(ctx).await?;
+    let node_list: Option<Vec<Node>> = list_cluster(ctx).await?;
+    let templates = collect_template_resources(ctx, warnings).await?;
+
+    // 클러스터 전체 allocatable 및 현재 request
+    let allocatable = node_list.map(|node_list| {
+        let mut allocatable_cpu = Cpu::default();
+        let mut allocatable_memory = Memory::default();
+        for node in node_list {
+            let source = WarningSource::new("Node", &node.metadata);
+            let allocatable = node.status.and_then(|s| s.allocatable);
+            allocatable_cpu += extract_quantity(&allocatable, "allocatable", "cpu", parse_cpu, &source, warnings);
+            allocatable_memory += extract_quantity(&allocatable, "allocatable", "memory", parse_memory, &source, warnings);
+        }
+        (allocatable_cpu, allocatable_memory)
+    });
+
+    // Pod 목록은 페이지 단위로 받아 바로 합산 (Node 뷰 TOTAL과 같이 완료된 Pod는 제외)
+    let mut cluster = Resources::new();
+    for_each_namespaced(ctx, |pod: Pod| {
+        if !counts_toward_requests(&pod) {
+            return;
+        }
+        if let Some(spec) = &pod.spec {
+            cluster.add_pod_spec(spec, &WarningSource::new("Pod", &pod.metadata), warnings);
+        }
+    })
+    .await?;
+
+    let mut rows = Vec::new();
+    for hpa in hpa_list {
+        let namespace = hpa.metadata.namespace.unwrap_or_default();
+        let name = hpa.metadata.name.unwrap_or_default();
+        let (target_kind, target_name, min_replicas, max_replicas) = match &hpa.spec {
+            Some(spec) => (
+                spec.scale_target_ref.kind.clone(),
+                spec.scale_target_ref.name.clone(),
+                spec.min_replicas.unwrap_or(1),
+                spec.max_replicas,
+            ),
+            None => (String::new(), String::new(), 0, 0),
+        };
+        let current_replicas = hpa.status.as_ref().and_then(|s| s.current_replicas).unwrap_or(0);
+        let desired_replicas = hpa.status.as_ref().map(|s| s.desired_replicas).unwrap_or(0);
+
+        // Deployment/StatefulSet/ReplicaSet 이외의 대상은 템플릿을 알 수 없으므로 0으로 처리
+        let per_replica = templates
+            .get(&(namespace.clone(), target_kind.clone(), target_name.clone()))
+            .cloned()
+            .unwrap_or_else(Resources::new);
+
+        rows.push(HpaRow {
+            namespace,
+            name,
+            target: format!("{}/{}", target_kind, target_name),
+            current_replicas,
+            desired_replicas,
+            min_replicas,
+            max_replicas,
+            current: per_replica.scaled(current_replicas as i64),
+            projected: per_replica.scaled(max_replicas as i64),
+        });
+    }
+
+    Ok(HpaData {
+        rows,
+        cluster,
+        allocatable,
+    })
+}
+
+impl HpaData {
+    // 모든 HPA가 maxReplicas로 스케일되었을 때의 클러스터 request와 allocatable 비교
+    pub fn summary(&self) -> String {
+        // 같은 워크로드를 대상으로 하는 HPA가 여럿이면 한 번만 (maxReplicas가 가장 큰 HPA 기준)
+        let mut targets: HashMap<(&str, &str), &HpaRow> = HashMap::new();
+        for row in &self.rows {
+            let target = targets.entry((row.namespace.as_str(), row.target.as_str())).or_insert(row);
+            if row.max_replicas > target.max_replicas {
+                *target = row;
+            }
+        }
+
+        let mut worst_case = self.cluster.clone();
+        for row in targets.values() {
+            worst_case.sub(&row.current);
+            worst_case.add(&row.projected);
+        }
+
+        match self.allocatable {
+            Some((allocatable_cpu, allocatable_memory)) => format!(
+                "Worst case: CPU Req. {} / {} ({}) | Mem Req. {} / {} ({})",
+                format_cpu(worst_case.cpu_request),
+                format_cpu(allocatable_cpu),
+                format_ratio(worst_case.cpu_request.as_nanocores(), allocatable_cpu.as_nanocores()),
+                format_memory(worst_case.memory_request),
+                format_memory(allocatable_memory),
+                format_ratio(worst_case.memory_request.as_bytes(), allocatable_memory.as_bytes()),
+            ),
+            None => format!(
+                "Worst case: CPU Req. {} | Mem Req. {} (allocatable hidden: no permission to list nodes)",
+                format_cpu(worst_case.cpu_request),
+                format_memory(worst_case.memory_request),
+            ),
+        }
+    }
+}
+
+pub const HPA_COLUMNS: &[ColumnDef<HpaRow>] = &[
+    ColumnDef::text("Namespace", 20, |row| CellValue::Text(row.namespace.clone())),
+    ColumnDef::text("HPA Name", 25, |row| CellValue::Text(row.name.clone())),
+    ColumnDef::text("Target", 30, |row| CellValue::Text(row.target.clone())),
+    ColumnDef::number("Current", 8, |row| CellValue::Number(Some(row.current_replicas.into()))),
+    ColumnDef::number("Desired", 8, |row| CellValue::Number(Some(row.desired_replicas.into()))),
+    ColumnDef::number("Min", 6, |row| CellValue::Number(Some(row.min_replicas.into()))),
+    ColumnDef::number("Max", 6, |row| CellValue::Number(Some(row.max_replicas.into()))),
+    ColumnDef::total("CPU Req.", 10, |row| CellValue::Cpu(row.current.cpu_request)),
+    ColumnDef::total("Mem Req.", 10, |row| CellValue::Memory(row.current.memory_request)),
+    ColumnDef::total("CPU Req. @Max", 14, |row| CellValue::Cpu(row.projected.cpu_request)),
+    ColumnDef::total("Mem Req. @Max", 14, |row| CellValue::Memory(row.projected.memory_request)),
+];
+
+pub fn hpa_table(data: &HpaData, search_config: Option<SearchConfig>, sort_config: Option<SortConfig>) -> Table {
+    Table::build(&data.rows, HPA_COLUMNS, search_config, sort_config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(name: &str, target: &str, current_replicas: i32, max_replicas: i32) -> HpaRow {
+        let per_replica = Resources { cpu_request: Cpu::from_nanocores(1_000_000_000), ..Default::default() };
+        HpaRow {
+            namespace: "default".to_string(),
+            name: name.to_string(),
+            target: target.to_string(),
+            current_replicas,
+            desired_replicas: current_replicas,
+            min_replicas: 1,
+            max_replicas,
+            current: per_replica.scaled(current_replicas as i64),
+            projected: per_replica.scaled(max_replicas as i64),
+        }
+    }
+
+    #[test]
+    fn test_summary_counts_each_target_once() {
+        let data = HpaData {
+            // 두 HPA가 같은 Deployment를 대상으로 함 (현재 2 replica)
+            rows: vec![row("web-cpu", "Deployment/web", 2, 5), row("web-memory", "Deployment/web", 2, 8)],
+            cluster: Resources { cpu_request: Cpu::from_nanocores(4_000_000_000), ..Default::default() },
+            allocatable: Some((Cpu::from_nanocores(20_000_000_000), Memory::default())),
+        };
+        // 4 - 2 + 8 = 10 CPU
+        assert!(data.summary().starts_with("Worst case: CPU Req. 10 / 20 (50%)"), "{}", data.summary());
+    }
+}
@@ -0,0 +1,121 @@
+// list.rs
+use kube::{Api, Resource};
+use kube::api::{ListParams, ObjectList};
+use k8s_openapi::{ClusterResourceScope, NamespaceResourceScope};
+use serde::de::DeserializeOwned;
+use std::fmt::Debug;
+
+use crate::api::context::KubeContext;
+use crate::models::error::AppError;
+
+fn is_forbidden(err: &kube::Error) -> bool {
+    matches!(err, kube::Error::Api(response) if response.code == 403)
+}
+
+fn is_gone(err: &kube::Error) -> bool {
+    matches!(err, kube::Error::Api(response) if response.code == 410)
+}
+
+enum Page<K: Clone> {
+    Items(ObjectList<K>),
+    Forbidden,
+    Expired, // continue token 만료 (410 Gone)
+}
+
+// limit/continue로 한 페이지씩 조회하며 항목마다 f 호출
+// 전체 목록을 메모리에 올리지 않음, 권한이 없으면(403이고 allow_forbidden) false 반환
+async fn for_each_page<K, F>(ctx: &KubeContext, api: &Api<K>, allow_forbidden: bool, f: &mut F) -> Result<bool, AppError>
+where
+    K: Resource + Clone + DeserializeOwned + Debug,
+    F: FnMut(K),
+{
+    let mut continue_token: Option<String> = None;
+    loop {
+        let mut list_params = ListParams::default().limit(ctx.request.page_size);
+        if let Some(token) = &continue_token {
+            list_params = list_params.continue_token(token);
+        }
+
+        let page = ctx.request(|| async {
+            match api.list(&list_params).await {
+                Ok(list) => Ok(Page::Items(list)),
+                Err(e) if allow_forbidden && is_forbidden(&e) => Ok(Page::Forbidden),
+                // 큰 목록을 받는 도중 etcd compaction으로 continue token이 만료됨
+                Err(e) if continue_token.is_some() && is_gone(&e) => Ok(Page::Expired),
+                Err(e) => Err(e),
+            }
+        })
+        .await?;
+        let list = match page {
+            Page::Items(list) => list,
+            Page::Forbidden => return Ok(false),
+            // 이미 받은 항목은 f로 합산되었으므로 처음부터 다시 받으면 중복됨: 에러로 알리고 다음 조회에서 새로 시작
+            Page::Expired => {
+                let progress = ctx.progress.snapshot();
+                return Err(AppError::KubeError(format!(
+                    "Listing {} expired after {} items (continue token expired, 410 Gone); refresh to list again, or raise --page-size to need fewer pages",
+                    progress.kind, progress.loaded
+                )));
+            }
+        };
+
+        ctx.progress.advance(list.items.len(), list.metadata.remaining_item_count);
+        for item in list.items {
+            f(item);
+        }
+
+        continue_token = list.metadata.continue_.filter(|token| !token.is_empty());
+        if continue_token.is_none() {
+            return Ok(true);
+        }
+    }
+}
+
+// namespaced 리소스를 페이지 단위로 순회
+// scope가 지정되면 Api::all 대신 namespace별로 Api::namespaced 조회
+pub async fn for_each_namespaced<K, F>(ctx: &KubeContext, mut f: F) -> Result<(), AppError>
+where
+    K: Resource<Scope = NamespaceResourceScope> + Clone + DeserializeOwned + Debug,
+    <K as Resource>::DynamicType: Default,
+    F: FnMut(K),
+{
+    ctx.progress.start(&K::plural(&Default::default()));
+    if ctx.scope.is_cluster_wide() {
+        let api: Api<K> = Api::all(ctx.client.clone());
+        for_each_page(ctx, &api, false, &mut f).await?;
+        return Ok(());
+    }
+
+    for namespace in &ctx.scope.namespaces {
+        let api: Api<K> = Api::namespaced(ctx.client.clone(), namespace);
+        for_each_page(ctx, &api, false, &mut f)
+            .await
+            .map_err(|e| AppError::KubeError(format!("{} (namespace: {})", e, namespace)))?;
+    }
+    Ok(())
+}
+
+// namespaced 리소스 목록 조회 (항목 수가 적은 리소스용)
+pub async fn list_namespaced<K>(ctx: &KubeContext) -> Result<Vec<K>, AppError>
+where
+    K: Resource<Scope = NamespaceResourceScope> + Clone + DeserializeOwned + Debug,
+    <K as Resource>::DynamicType: Default,
+{
+    let mut items = Vec::new();
+    for_each_namespaced(ctx, |item| items.push(item)).await?;
+    Ok(items)
+}
+
+// cluster 리소스 목록 조회
+// 권한이 없으면(403) 에러 대신 None 반환
+pub async fn list_cluster<K>(ctx: &KubeContext) -> Result<Option<Vec<K>>, AppError>
+where
+    K: Resource<Scope = ClusterResourceScope> + Clone + DeserializeOwned + Debug,
+    <K as Resource>::DynamicType: Default,
+{
+    ctx.progress.start(&K::plural(&Default::default()));
+    let api: Api<K> = Api::all(ctx.client.clone());
+    let mut items = Vec::new();
+    let allowed = for_each_page(ctx, &api, true, &mut |item| items.push(item)).await?;
+    Ok(allowed.then_some(items))
+}
@@ -1,3 +1,11 @@
+pub mod access;
+pub mod context;
+pub mod dataset;
+pub mod list;
 pub mod namespace;
 pub mod pod;
-pub mod node;
\ No newline at end of file
+pub mod node;
+pub mod hpa;
+pub mod storage;
+pub mod priority;
+pub mod snapshot;
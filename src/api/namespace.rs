@@ -1,116 +1,73 @@
-use kube::{Api, Client};
 use k8s_openapi::api::core::v1::{Namespace, Pod};
-use kube::api::ListParams;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use crate::api::context::KubeContext;
+use crate::api::list::{for_each_namespaced, list_cluster};
+use crate::api::pod::{pod_row, RequestTotals};
 use crate::models::resource::Resources;
-use crate::util::common::{format_cpu, format_memory};
 use crate::AppError;
-use crate::models::config::SortConfig;
-
-async fn collect_namespace_resources(
-    pod_list: Vec<Pod>
-) -> HashMap<String, Resources> {
-    let mut namespace_resources: HashMap<String, Resources> = HashMap::new();
-
-    for pod in pod_list {
-        if let Some(namespace) = &pod.metadata.namespace {
-            let resources = namespace_resources
-                .entry(namespace.clone())
-                .or_insert_with(Resources::new);
-
-            if let Some(spec) = &pod.spec {
-                for container in &spec.containers {
-                    if let Some(container_resources) = &container.resources {
-                        resources.add_container_resources(container_resources);
-                    }
-                }
-            }
-        }
-    }
-    namespace_resources
+use crate::models::config::{SearchConfig, SortConfig};
+use crate::models::table::{CellValue, ColumnDef, Table};
+use crate::models::warning::ParseWarning;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct NamespaceRow {
+    pub name: String,
+    pub resources: Resources,
 }
 
-pub async fn handle_namespace_command(sort_config: Option<SortConfig>) -> Result<Vec<Vec<String>>, AppError> {
-    let client = Client::try_default()
-        .await
-        .map_err(|e| AppError::KubeError(e.to_string()))?;
-
-    let namespaces: Api<Namespace> = Api::all(client.clone());
-    let pods: Api<Pod> = Api::all(client);
-
-    let namespace_list = namespaces.list(&ListParams::default())
-        .await
-        .map_err(|e| AppError::KubeError(e.to_string()))?;
-    
-    let pod_list = pods.list(&ListParams::default())
-        .await
-        .map_err(|e| AppError::KubeError(e.to_string()))?;
-
-    let pod_items = pod_list.items;
-    let namespace_resources = collect_namespace_resources(pod_items).await;
+pub async fn fetch_namespace_rows(ctx: &KubeContext, warnings: &mut Vec<ParseWarning>) -> Result<Vec<NamespaceRow>, AppError> {
+    // Pod 목록은 페이지 단위로 받아 namespace별로 바로 합산
+    let mut totals = RequestTotals::default();
+    for_each_namespaced(ctx, |pod: Pod| totals.add(&pod_row(pod, warnings))).await?;
 
-    let mut table_rows = Vec::new();
-    let mut total_resources = Resources::new();
-
-    for ns in namespace_list {
-        let namespace_name = ns.metadata.name.unwrap_or_default();
-        let resources = namespace_resources
-            .get(&namespace_name)
-            .cloned()
-            .unwrap_or_else(Resources::new);
-
-        total_resources.add(&resources);
-        table_rows.push((namespace_name, resources));
-    }
+    build_namespace_rows(ctx, &totals.by_namespace).await
+}
 
-    // 정렬
-    if let Some(sort_config) = sort_config {
-        table_rows.sort_by(|a, b| {
-            let column_index = sort_config.column;
-            let compare = match column_index {
-                0 => a.0.cmp(&b.0),
-                1 => a.1.cpu_request.0.cmp(&b.1.cpu_request.0),
-                2 => a.1.cpu_limit.0.cmp(&b.1.cpu_limit.0),
-                3 => a.1.memory_request.0.cmp(&b.1.memory_request.0),
-                4 => a.1.memory_limit.0.cmp(&b.1.memory_limit.0),
-                _ => std::cmp::Ordering::Equal,
-            };
+// namespace별 Pod request 합계로 namespace 행 생성 (Pod가 없는 namespace도 포함)
+pub async fn build_namespace_rows(
+    ctx: &KubeContext,
+    namespace_resources: &HashMap<String, Resources>,
+) -> Result<Vec<NamespaceRow>, AppError> {
+    // scope가 지정되면 접근 가능한 namespace만 표시
+    // namespace 조회 권한이 없으면 Pod가 존재하는 namespace로 대체
+    let namespace_names: Vec<String> = if ctx.scope.is_cluster_wide() {
+        match list_cluster::<Namespace>(ctx).await? {
+            Some(namespace_list) => namespace_list
+                .into_iter()
+                .map(|ns| ns.metadata.name.unwrap_or_default())
+                .collect(),
+            None => namespace_resources.keys().cloned().collect(),
+        }
+    } else {
+        ctx.scope.namespaces.clone()
+    };
 
-            match column_index {
-                0 => compare,
-                _ => compare.reverse()
+    let rows = namespace_names
+        .into_iter()
+        .map(|namespace_name| {
+            let resources = namespace_resources
+                .get(&namespace_name)
+                .cloned()
+                .unwrap_or_else(Resources::new);
+            NamespaceRow {
+                name: namespace_name,
+                resources,
             }
-        });
-    }
-
-    // Total 행 추가
-    table_rows.push((
-        "TOTAL".to_string(),
-        total_resources,
-    ));
+        })
+        .collect();
 
-    // 결과 데이터 생성
-    let mut result = Vec::new();
-    
-    // 헤더 추가
-    result.push(vec![
-        "Namespace".to_string(),
-        "CPU Req.".to_string(),
-        "CPU Lim.".to_string(),
-        "Mem Req.".to_string(),
-        "Mem Lim.".to_string(),
-    ]);
+    Ok(rows)
+}
 
-    // 데이터 행 추가
-    for (namespace_name, resources) in table_rows {
-        result.push(vec![
-            namespace_name,
-            format_cpu(resources.cpu_request),
-            format_cpu(resources.cpu_limit),
-            format_memory(resources.memory_request),
-            format_memory(resources.memory_limit),
-        ]);
-    }
+pub const NAMESPACE_COLUMNS: &[ColumnDef<NamespaceRow>] = &[
+    ColumnDef::text("Namespace", 30, |row| CellValue::Text(row.name.clone())),
+    ColumnDef::total("CPU Req.", 15, |row| CellValue::Cpu(row.resources.cpu_request)),
+    ColumnDef::total("CPU Lim.", 15, |row| CellValue::Cpu(row.resources.cpu_limit)),
+    ColumnDef::total("Mem Req.", 15, |row| CellValue::Memory(row.resources.memory_request)),
+    ColumnDef::total("Mem Lim.", 15, |row| CellValue::Memory(row.resources.memory_limit)),
+];
 
-    Ok(result)
-}
\ No newline at end of file
+pub fn namespace_table(rows: &[NamespaceRow], search_config: Option<SearchConfig>, sort_config: Option<SortConfig>) -> Table {
+    Table::build(rows, NAMESPACE_COLUMNS, search_config, sort_config)
+}
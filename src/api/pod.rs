@@ -1,142 +1,158 @@
-use std::cmp::Ordering;
-
-use kube::{Api, Client};
 use k8s_openapi::api::core::v1::Pod;
-use kube::api::ListParams;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use crate::api::context::KubeContext;
+use crate::api::list::for_each_namespaced;
 use crate::models::resource::Resources;
-use crate::util::common::{format_cpu, format_memory};
 use crate::AppError;
 use crate::models::config::SortConfig;
 use crate::models::config::SearchConfig;
+use crate::models::table::{CellValue, ColumnDef, Table};
+use crate::models::warning::{ParseWarning, WarningSource};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PodRow {
+    pub namespace: String,
+    pub name: String,
+    pub status: String,
+    pub node: String,
+    pub resources: Resources,
+    pub priority_class: String,
+    pub priority: Option<i32>,
+    // 소유 워크로드 ("Deployment/api"), 이전 버전의 기록에는 없음
+    #[serde(default)]
+    pub workload: String,
+}
+
+// 완료된 Pod (Succeeded/Failed)는 노드의 리소스를 차지하지 않으므로 request 합계에서 제외
+// request를 합산하는 모든 곳(뷰, report, check, diff, HPA, priority)이 이 기준을 사용
+fn phase_counts_toward_requests(phase: &str) -> bool {
+    phase != "Succeeded" && phase != "Failed"
+}
+
+// 행으로 변환하지 않고 합산하는 곳 (HPA, priority)
+pub fn counts_toward_requests(pod: &Pod) -> bool {
+    let phase = pod.status.as_ref().and_then(|status| status.phase.as_deref());
+    phase_counts_toward_requests(phase.unwrap_or_default())
+}
+
+impl PodRow {
+    pub fn counts_toward_requests(&self) -> bool {
+        phase_counts_toward_requests(&self.status)
+    }
+}
 
-pub async fn handle_pod_command(search_config: Option<SearchConfig>, sort_config: Option<SortConfig>) -> Result<Vec<Vec<String>>, AppError> {
-    let client = Client::try_default().await.map_err(|e| AppError::KubeError(e.to_string()))?;
-    let pods: Api<Pod> = Api::all(client);
-
-    let pod_list = pods.list(&ListParams::default()).await.map_err(|e| AppError::KubeError(e.to_string()))?;
+pub async fn fetch_pod_rows(ctx: &KubeContext, warnings: &mut Vec<ParseWarning>) -> Result<Vec<PodRow>, AppError> {
     let mut pod_rows = Vec::new();
 
-    let mut total_resources = Resources::new();
-
-    // Pod 데이터 수집
-    for pod in pod_list {
-        let namespace = pod.metadata.namespace.unwrap_or_default();
-        let name = pod.metadata.name.unwrap_or_default();
-        let status = pod
-            .status
-            .as_ref()
-            .and_then(|s| s.phase.clone())
-            .unwrap_or_default();
-        let node = pod
-            .spec
-            .as_ref()
-            .and_then(|spec| spec.node_name.clone())
-            .unwrap_or_default();
-
-        // 정렬
-        if let Some(search_config) = search_config.clone() {
-            let column_index = search_config.column;
-            let word = search_config.get_word();
-            let matched = match column_index {
-                0 => namespace == word,
-                1 => name == word,
-                2 => status == word,
-                3 => node == word,
-                _ => true,
-            };
-
-            if !matched {
-                continue;
-            }
-        }
-
-        let mut pod_resources = Resources::new();
-
-        if let Some(spec) = &pod.spec {
-            for container in &spec.containers {
-                if let Some(resources) = &container.resources {
-                    pod_resources.add_container_resources(resources);
-                }
+    // Pod 데이터 수집 (페이지 단위로 받아 바로 행으로 변환)
+    for_each_namespaced(ctx, |pod: Pod| pod_rows.push(pod_row(pod, warnings))).await?;
+
+    Ok(pod_rows)
+}
+
+// controller ownerReference로 워크로드 이름 결정 (소유자가 없으면 "Pod/<name>")
+fn workload_name(metadata: &ObjectMeta) -> String {
+    let owner = metadata
+        .owner_references
+        .iter()
+        .flatten()
+        .find(|owner| owner.controller == Some(true));
+    match owner {
+        Some(owner) if owner.kind == "ReplicaSet" => {
+            // Deployment가 만든 ReplicaSet은 이름 끝에 pod-template-hash가 붙음
+            let hash = metadata.labels.as_ref().and_then(|labels| labels.get("pod-template-hash"));
+            match hash.and_then(|hash| owner.name.strip_suffix(&format!("-{}", hash))) {
+                Some(deployment) => format!("Deployment/{}", deployment),
+                None => format!("ReplicaSet/{}", owner.name),
             }
         }
-
-        total_resources.add(&pod_resources);
-
-        pod_rows.push((
-            namespace,
-            name,
-            status,
-            node,
-            pod_resources.cpu_request,
-            pod_resources.cpu_limit,
-            pod_resources.memory_request,
-            pod_resources.memory_limit,
-        ));
+        Some(owner) => format!("{}/{}", owner.kind, owner.name),
+        None => format!("Pod/{}", metadata.name.as_deref().unwrap_or_default()),
     }
-
-    // 정렬
-    if let Some(sort_config) = sort_config {
-        pod_rows.sort_by(|a, b| {
-            let column_index = sort_config.column;
-            let compare = match column_index {
-                0 => a.0.cmp(&b.0),
-                1 => a.1.cmp(&b.1),
-                2 => a.2.cmp(&b.2),
-                3 => a.3.cmp(&b.3),
-                4 => a.4.0.cmp(&b.4.0),
-                5 => a.5.0.cmp(&b.5.0),
-                6 => a.6.0.cmp(&b.6.0),
-                7 => a.7.0.cmp(&b.7.0),
-                _ => Ordering::Equal,
-            };
-
-            match column_index {
-                0 | 1 | 2 | 3 => compare,
-                _ => compare.reverse()
-            }
-        });
+}
+
+// Pod 하나를 행으로 변환 (목록 조회와 watch에서 공통 사용)
+pub fn pod_row(pod: Pod, warnings: &mut Vec<ParseWarning>) -> PodRow {
+    let source = WarningSource::new("Pod", &pod.metadata);
+    let workload = workload_name(&pod.metadata);
+    let namespace = pod.metadata.namespace.unwrap_or_default();
+    let name = pod.metadata.name.unwrap_or_default();
+    let status = pod
+        .status
+        .as_ref()
+        .and_then(|s| s.phase.clone())
+        .unwrap_or_default();
+    let node = pod
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.node_name.clone())
+        .unwrap_or_default();
+    let priority_class = pod
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.priority_class_name.clone())
+        .unwrap_or_default();
+    let priority = pod
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.priority);
+
+    let mut pod_resources = Resources::new();
+
+    if let Some(spec) = &pod.spec {
+        pod_resources.add_pod_spec(spec, &source, warnings);
     }
 
-    // Total 행 추가
-    pod_rows.push((
-        "TOTAL".to_string(),
-        "".to_string(),
-        "".to_string(),
-        "".to_string(),
-        total_resources.cpu_request,
-        total_resources.cpu_limit,
-        total_resources.memory_request,
-        total_resources.memory_limit,
-    ));
-
-    // 헤더 추가 및 데이터 변환
-    let mut result = Vec::new();
-    
-    // 헤더 추가
-    result.push(vec![
-        "Namespace".to_string(),
-        "Pod Name".to_string(),
-        "Status".to_string(),
-        "Node".to_string(),
-        "CPU Req.".to_string(),
-        "CPU Lim.".to_string(),
-        "Mem Req.".to_string(),
-        "Mem Lim.".to_string(),
-    ]);
-
-    // 데이터 행 추가
-    for (namespace, name, status, node, cpu_request, cpu_limit, memory_request, memory_limit) in pod_rows {
-        result.push(vec![
-            namespace,
-            name,
-            status,
-            node,
-            format_cpu(cpu_request),
-            format_cpu(cpu_limit),
-            format_memory(memory_request),
-            format_memory(memory_limit),
-        ]);
+    PodRow {
+        namespace,
+        name,
+        status,
+        node,
+        resources: pod_resources,
+        priority_class,
+        priority,
+        workload,
     }
-
-    Ok(result)
-}
\ No newline at end of file
+}
+
+// 노드별, namespace별 Pod request 합계 (완료된 Pod는 제외)
+// Node/Namespace 뷰와 report, serve, check, --record가 모두 이 합계를 사용
+#[derive(Default)]
+pub struct RequestTotals {
+    pub by_node: HashMap<String, Resources>,
+    pub by_namespace: HashMap<String, Resources>,
+}
+
+impl RequestTotals {
+    pub fn add(&mut self, pod: &PodRow) {
+        if !pod.counts_toward_requests() {
+            return;
+        }
+        // 아직 스케줄되지 않은 Pod는 노드 합계에서 제외
+        if !pod.node.is_empty() {
+            self.by_node.entry(pod.node.clone()).or_default().add(&pod.resources);
+        }
+        if !pod.namespace.is_empty() {
+            self.by_namespace.entry(pod.namespace.clone()).or_default().add(&pod.resources);
+        }
+    }
+}
+
+pub const POD_COLUMNS: &[ColumnDef<PodRow>] = &[
+    ColumnDef::text("Namespace", 20, |row| CellValue::Text(row.namespace.clone())),
+    ColumnDef::text("Pod Name", 35, |row| CellValue::Text(row.name.clone())),
+    ColumnDef::text("Status", 15, |row| CellValue::Text(row.status.clone())),
+    ColumnDef::text("Node", 25, |row| CellValue::Text(row.node.clone())),
+    ColumnDef::total("CPU Req.", 15, |row| CellValue::Cpu(row.resources.cpu_request)),
+    ColumnDef::total("CPU Lim.", 15, |row| CellValue::Cpu(row.resources.cpu_limit)),
+    ColumnDef::total("Mem Req.", 15, |row| CellValue::Memory(row.resources.memory_request)),
+    ColumnDef::total("Mem Lim.", 15, |row| CellValue::Memory(row.resources.memory_limit)),
+    ColumnDef::text("Priority Class", 20, |row| CellValue::Text(row.priority_class.clone())),
+    ColumnDef::number("Priority", 10, |row| CellValue::Number(row.priority.map(i64::from))),
+];
+
+pub fn pod_table(rows: &[PodRow], search_config: Option<SearchConfig>, sort_config: Option<SortConfig>) -> Table {
+    Table::build(rows, POD_COLUMNS, search_config, sort_config)
+}
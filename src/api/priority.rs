@@ -0,0 +1,90 @@
+// priority.rs
+use k8s_openapi::api::core::v1::Pod;
+use std::collections::HashMap;
+
+use crate::api::context::KubeContext;
+use crate::api::list::for_each_namespaced;
+use crate::api::pod::counts_toward_requests;
+use crate::models::error::AppError;
+use crate::models::config::{SearchConfig, SortConfig};
+use crate::models::table::{CellValue, ColumnDef, Table};
+use crate::models::warning::{ParseWarning, WarningSource};
+use crate::models::resource::Resources;
+
+const NO_PRIORITY_CLASS: &str = "<none>";
+
+pub struct PriorityBand {
+    pub node: String,
+    pub priority_class: String,
+    pub priority: i32,
+    pub pods: i64,
+    pub resources: Resources,
+    // 이 band보다 낮은 priority를 가진 Pod의 request 합계 (preemption으로 회수 가능한 양)
+    pub preemptible: Resources,
+}
+
+pub async fn fetch_priority_bands(ctx: &KubeContext, warnings: &mut Vec<ParseWarning>) -> Result<Vec<PriorityBand>, AppError> {
+    // (node, priority, priority class) 별로 그룹화 (페이지 단위로 받아 바로 합산)
+    let mut bands: HashMap<(String, i32, String), (i64, Resources)> = HashMap::new();
+    for_each_namespaced(ctx, |pod: Pod| {
+        // 완료된 Pod는 자리를 차지하지 않으므로 preemption으로 회수할 것도 없음
+        if !counts_toward_requests(&pod) {
+            return;
+        }
+        let Some(spec) = &pod.spec else { return };
+        let Some(node) = spec.node_name.clone() else { return };
+        let priority_class = spec
+            .priority_class_name
+            .clone()
+            .unwrap_or_else(|| NO_PRIORITY_CLASS.to_string());
+        let priority = spec.priority.unwrap_or(0);
+
+        let band = bands.entry((node, priority, priority_class)).or_default();
+        band.0 += 1;
+        band.1.add_pod_spec(spec, &WarningSource::new("Pod", &pod.metadata), warnings);
+    })
+    .await?;
+
+    let mut band_rows: Vec<PriorityBand> = bands
+        .into_iter()
+        .map(|((node, priority, priority_class), (pods, resources))| PriorityBand {
+            node,
+            priority_class,
+            priority,
+            pods,
+            resources,
+            preemptible: Resources::new(),
+        })
+        .collect();
+
+    // 노드 이름 오름차순, priority 내림차순으로 기본 정렬
+    band_rows.sort_by(|a, b| a.node.cmp(&b.node).then(b.priority.cmp(&a.priority)));
+
+    // 같은 노드에서 더 낮은 priority band의 request를 누적
+    for i in 0..band_rows.len() {
+        let mut preemptible = Resources::new();
+        for lower in band_rows[i + 1..].iter().take_while(|b| b.node == band_rows[i].node) {
+            if lower.priority < band_rows[i].priority {
+                preemptible.add(&lower.resources);
+            }
+        }
+        band_rows[i].preemptible = preemptible;
+    }
+
+    Ok(band_rows)
+}
+
+pub const PRIORITY_COLUMNS: &[ColumnDef<PriorityBand>] = &[
+    ColumnDef::text("Node", 25, |band| CellValue::Text(band.node.clone())),
+    ColumnDef::text("Priority Class", 25, |band| CellValue::Text(band.priority_class.clone())),
+    ColumnDef::number("Priority", 12, |band| CellValue::Number(Some(band.priority.into()))),
+    ColumnDef::total("Pods", 8, |band| CellValue::Count(band.pods)),
+    ColumnDef::total("CPU Req.", 12, |band| CellValue::Cpu(band.resources.cpu_request)),
+    ColumnDef::total("Mem Req.", 12, |band| CellValue::Memory(band.resources.memory_request)),
+    ColumnDef::number("Preempt. CPU", 14, |band| CellValue::Cpu(band.preemptible.cpu_request)),
+    ColumnDef::number("Preempt. Mem", 14, |band| CellValue::Memory(band.preemptible.memory_request)),
+];
+
+pub fn priority_table(bands: &[PriorityBand], search_config: Option<SearchConfig>, sort_config: Option<SortConfig>) -> Table {
+    Table::build(bands, PRIORITY_COLUMNS, search_config, sort_config)
+}
@@ -0,0 +1,158 @@
+// snapshot.rs
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
+
+use crate::api::context::KubeContext;
+use crate::api::dataset::{fetch_cluster_usage, ClusterUsage, Dataset};
+use crate::api::namespace::NamespaceRow;
+use crate::api::node::NodeData;
+use crate::api::pod::PodRow;
+use crate::models::app::ViewMode;
+use crate::models::error::AppError;
+
+// 기록 시점의 Node/Pod/Namespace 상태 (파일에는 한 줄에 하나씩 JSON으로 추가)
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub time: DateTime<Local>,
+    pub nodes: NodeData,
+    pub pods: Vec<PodRow>,
+    pub namespaces: Vec<NamespaceRow>,
+}
+
+impl Snapshot {
+    pub fn new(usage: ClusterUsage) -> Self {
+        Self {
+            time: Local::now(),
+            nodes: usage.nodes,
+            pods: usage.pods,
+            namespaces: usage.namespaces,
+        }
+    }
+
+    // replay에서 볼 수 있는 뷰의 데이터 (기록하지 않은 뷰는 None)
+    pub fn dataset(&self, view_mode: ViewMode) -> Option<Dataset> {
+        match view_mode {
+            ViewMode::Node => Some(Dataset::Node(self.nodes.clone())),
+            ViewMode::Pod => Some(Dataset::Pod(self.pods.clone())),
+            ViewMode::Namespace => Some(Dataset::Namespace(self.namespaces.clone())),
+            _ => None,
+        }
+    }
+}
+
+pub fn append(path: &Path, snapshot: &Snapshot) -> Result<(), AppError> {
+    let line = serde_json::to_string(snapshot)
+        .map_err(|e| AppError::ParseError(format!("Failed to serialize snapshot: {}", e)))?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| AppError::IoError(format!("Failed to open {}: {}", path.display(), e)))?;
+    writeln!(file, "{}", line).map_err(|e| AppError::IoError(format!("Failed to write {}: {}", path.display(), e)))
+}
+
+// 기록 파일을 시간순으로 읽음 (빈 줄은 무시)
+pub fn load(path: &Path) -> Result<Vec<Snapshot>, AppError> {
+    let file = File::open(path).map_err(|e| AppError::IoError(format!("Failed to open {}: {}", path.display(), e)))?;
+    let mut snapshots = Vec::new();
+    for (index, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let snapshot: Snapshot = serde_json::from_str(&line).map_err(|e| {
+            AppError::ParseError(format!("{} line {}: invalid snapshot: {}", path.display(), index + 1, e))
+        })?;
+        snapshots.push(snapshot);
+    }
+    if snapshots.is_empty() {
+        return Err(AppError::ParseError(format!("{} contains no snapshots", path.display())));
+    }
+    snapshots.sort_by_key(|snapshot| snapshot.time);
+    Ok(snapshots)
+}
+
+// 커맨드가 이미 조회한 데이터로 snapshot 하나를 추가 (report, check)
+pub fn record_usage(path: &Path, usage: &ClusterUsage) -> Result<(), AppError> {
+    append(path, &Snapshot::new(usage.clone()))
+}
+
+// 클러스터를 조회하여 snapshot 하나를 추가
+pub async fn record_once(ctx: &KubeContext, path: &Path) -> Result<(), AppError> {
+    let (usage, _) = fetch_cluster_usage(&ctx.for_fetch()).await?;
+    append(path, &Snapshot::new(usage))
+}
+
+// --record 진행 상황 (UI 상태 표시줄에 표시)
+pub struct RecordStatus {
+    pub path: PathBuf,
+    pub count: usize,
+    pub last_error: Option<AppError>,
+}
+
+impl RecordStatus {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path, count: 0, last_error: None }
+    }
+}
+
+// interval마다 snapshot 추가 (실패해도 다음 주기에 계속)
+// status가 없으면(serve) 실패를 stderr로 출력
+pub async fn record_loop(
+    ctx: KubeContext,
+    path: PathBuf,
+    interval: Duration,
+    status: Option<Arc<Mutex<RecordStatus>>>,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    loop {
+        ticker.tick().await;
+        let result = record_once(&ctx, &path).await;
+        match &status {
+            Some(status) => {
+                let mut status = status.lock().unwrap();
+                match result {
+                    Ok(()) => {
+                        status.count += 1;
+                        status.last_error = None;
+                    }
+                    Err(e) => status.last_error = Some(e),
+                }
+            }
+            None => {
+                if let Err(e) = result {
+                    eprintln!("Failed to record snapshot to {}: {}", path.display(), e);
+                }
+            }
+        }
+    }
+}
+
+// --replay에서 현재 보고 있는 snapshot 위치
+pub struct Replay {
+    pub snapshots: Vec<Snapshot>,
+    pub position: usize,
+}
+
+impl Replay {
+    pub fn new(snapshots: Vec<Snapshot>) -> Self {
+        Self { snapshots, position: 0 }
+    }
+
+    pub fn current(&self) -> &Snapshot {
+        &self.snapshots[self.position]
+    }
+
+    // 앞(+)/뒤(-)로 이동, 범위를 벗어나면 처음/마지막에서 멈춤
+    pub fn seek(&mut self, offset: isize) {
+        let last = self.snapshots.len().saturating_sub(1);
+        self.position = self.position.saturating_add_signed(offset).min(last);
+    }
+}
@@ -0,0 +1,126 @@
+// storage.rs
+use k8s_openapi::api::core::v1::PersistentVolumeClaim;
+use std::collections::HashMap;
+
+use crate::api::context::KubeContext;
+use crate::api::list::for_each_namespaced;
+use crate::util::common::{extract_quantity, parse_memory};
+use crate::models::error::AppError;
+use crate::models::config::{SearchConfig, SortConfig};
+use crate::models::table::{CellValue, ColumnDef, Table};
+use crate::models::warning::{ParseWarning, WarningSource};
+use crate::models::resource::{Memory, StorageResources};
+
+const NO_STORAGE_CLASS: &str = "<none>";
+
+pub struct ClaimRow {
+    pub namespace: String,
+    pub name: String,
+    pub phase: String,
+    pub storage_class: String,
+    pub volume: String,
+    pub requested: Memory,
+    pub capacity: Memory,
+}
+
+pub async fn fetch_claims(ctx: &KubeContext, warnings: &mut Vec<ParseWarning>) -> Result<Vec<ClaimRow>, AppError> {
+    let mut claims = Vec::new();
+
+    // 페이지 단위로 받아 바로 행으로 변환
+    for_each_namespaced(ctx, |pvc: PersistentVolumeClaim| {
+        let source = WarningSource::new("PVC", &pvc.metadata);
+        let spec = pvc.spec.unwrap_or_default();
+        let status = pvc.status.unwrap_or_default();
+        // storageClassName이 없으면 구버전 annotation 확인
+        let storage_class = spec
+            .storage_class_name
+            .clone()
+            .or_else(|| {
+                pvc.metadata
+                    .annotations
+                    .as_ref()
+                    .and_then(|a| a.get("volume.beta.kubernetes.io/storage-class").cloned())
+            })
+            .unwrap_or_else(|| NO_STORAGE_CLASS.to_string());
+        let requests = spec.resources.and_then(|r| r.requests);
+
+        claims.push(ClaimRow {
+            namespace: pvc.metadata.namespace.unwrap_or_default(),
+            name: pvc.metadata.name.unwrap_or_default(),
+            phase: status.phase.unwrap_or_default(),
+            storage_class,
+            volume: spec.volume_name.unwrap_or_default(),
+            requested: extract_quantity(&requests, "requests", "storage", parse_memory, &source, warnings),
+            capacity: extract_quantity(&status.capacity, "capacity", "storage", parse_memory, &source, warnings),
+        });
+    })
+    .await?;
+
+    Ok(claims)
+}
+
+pub const PVC_COLUMNS: &[ColumnDef<ClaimRow>] = &[
+    ColumnDef::text("Namespace", 20, |claim| CellValue::Text(claim.namespace.clone())),
+    ColumnDef::text("PVC Name", 35, |claim| CellValue::Text(claim.name.clone())),
+    ColumnDef::text("Status", 10, |claim| CellValue::Text(claim.phase.clone())),
+    ColumnDef::text("Storage Class", 20, |claim| CellValue::Text(claim.storage_class.clone())),
+    ColumnDef::text("Volume", 45, |claim| CellValue::Text(claim.volume.clone())),
+    ColumnDef::total("Requested", 12, |claim| CellValue::Memory(claim.requested)),
+    ColumnDef::total("Capacity", 12, |claim| CellValue::Memory(claim.capacity)),
+];
+
+// Namespace 또는 Storage Class별로 묶은 PVC 합계
+pub struct StorageGroupRow {
+    pub name: String,
+    pub resources: StorageResources,
+}
+
+pub const STORAGE_COLUMNS: &[ColumnDef<StorageGroupRow>] = &[
+    ColumnDef::text("Namespace", 30, |group| CellValue::Text(group.name.clone())),
+    ColumnDef::total("PVCs", 10, |group| CellValue::Count(group.resources.claims)),
+    ColumnDef::total("Bound", 10, |group| CellValue::Count(group.resources.bound)),
+    ColumnDef::total("Pending", 10, |group| CellValue::Count(group.resources.pending)),
+    ColumnDef::total("Requested", 15, |group| CellValue::Memory(group.resources.requested)),
+    ColumnDef::total("Capacity", 15, |group| CellValue::Memory(group.resources.capacity)),
+];
+
+pub const STORAGE_CLASS_COLUMNS: &[ColumnDef<StorageGroupRow>] = &[
+    ColumnDef::text("Storage Class", 30, |group| CellValue::Text(group.name.clone())),
+    ColumnDef::total("PVCs", 10, |group| CellValue::Count(group.resources.claims)),
+    ColumnDef::total("Bound", 10, |group| CellValue::Count(group.resources.bound)),
+    ColumnDef::total("Pending", 10, |group| CellValue::Count(group.resources.pending)),
+    ColumnDef::total("Requested", 15, |group| CellValue::Memory(group.resources.requested)),
+    ColumnDef::total("Capacity", 15, |group| CellValue::Memory(group.resources.capacity)),
+];
+
+pub fn pvc_table(claims: &[ClaimRow], search_config: Option<SearchConfig>, sort_config: Option<SortConfig>) -> Table {
+    Table::build(claims, PVC_COLUMNS, search_config, sort_config)
+}
+
+fn group_claims<F>(claims: &[ClaimRow], group_key: F) -> Vec<StorageGroupRow>
+where
+    F: Fn(&ClaimRow) -> String,
+{
+    let mut groups: HashMap<String, StorageResources> = HashMap::new();
+    for claim in claims {
+        groups
+            .entry(group_key(claim))
+            .or_default()
+            .add_claim(&claim.phase, claim.requested, claim.capacity);
+    }
+
+    groups
+        .into_iter()
+        .map(|(name, resources)| StorageGroupRow { name, resources })
+        .collect()
+}
+
+pub fn storage_table(claims: &[ClaimRow], search_config: Option<SearchConfig>, sort_config: Option<SortConfig>) -> Table {
+    let groups = group_claims(claims, |claim| claim.namespace.clone());
+    Table::build(&groups, STORAGE_COLUMNS, search_config, sort_config)
+}
+
+pub fn storage_class_table(claims: &[ClaimRow], search_config: Option<SearchConfig>, sort_config: Option<SortConfig>) -> Table {
+    let groups = group_claims(claims, |claim| claim.storage_class.clone());
+    Table::build(&groups, STORAGE_CLASS_COLUMNS, search_config, sort_config)
+}
@@ -2,20 +2,28 @@ mod util;
 mod api;
 mod ui;
 mod models;
+mod output;
 
-use std::{io, path::Path};
-use std::time::Duration;
+use std::{io, io::IsTerminal, path::Path};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use crossterm::{
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use models::config::{SearchConfig, SortConfig};
+use clap::Parser;
+use models::cli::{Cli, Command, ListCommand};
+use models::access::Permissions;
+use models::config::{RequestConfig, ScopeConfig};
 use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
 use tui::{
     backend::CrosstermBackend, Terminal
 };
 
-use models::app::{AppState, ViewMode};
+use models::app::{AppState, FetchOutcome, ViewMode};
+use api::context::KubeContext;
+use api::snapshot::{RecordStatus, Replay};
 use ui::ui::draw_ui;
 use ui::event::handle_event;
 
@@ -45,26 +53,42 @@ impl Drop for TerminalSetup {
 
 
 
-async fn fetch_data_with_sort(
-    view_mode: ViewMode,
-    search_config: SearchConfig,
-    sort_config: SortConfig,
-) -> Result<Vec<Vec<String>>, AppError> {
-    match view_mode {
-        ViewMode::Node => api::node::handle_node_command(Some(sort_config))
-            .await
-            .map_err(|e| AppError::KubeError(e.to_string())),
-        ViewMode::Pod => api::pod::handle_pod_command(Some(search_config), Some(sort_config))
-            .await
-            .map_err(|e| AppError::KubeError(e.to_string())), // 추후 Pod도 정렬 추가 가능
-        ViewMode::Namespace => api::namespace::handle_namespace_command(Some(sort_config))
-            .await
-            .map_err(|e| AppError::KubeError(e.to_string())),
+async fn fetch_with_stats(ctx: KubeContext, view_mode: ViewMode) -> FetchOutcome {
+    let started = Instant::now();
+    let result = api::dataset::fetch_dataset(&ctx, view_mode).await;
+
+    FetchOutcome {
+        result,
+        latency: started.elapsed(),
+        retries: ctx.retry_count(),
     }
 }
 
 #[tokio::main]
 async fn main() -> Result<(), AppError> {
+    let cli = Cli::parse();
+
+    // 기록된 snapshot만 표시 (kubeconfig, 클러스터 접근 없음)
+    if let Some(path) = &cli.replay {
+        if cli.command.is_some() {
+            return Err(AppError::ParseError("--replay opens the UI and cannot be combined with a subcommand".to_string()));
+        }
+        let mut app_state = AppState::new();
+        app_state.replay = Some(Replay::new(api::snapshot::load(path)?));
+        return run_ui(None, app_state).await;
+    }
+
+    let scope_config = ScopeConfig::new(cli.namespaces);
+    let request_config = RequestConfig::new(cli.timeout, cli.retries, cli.page_size);
+
+    if let (Some(_), Some(command)) = (&cli.record, &cli.command) {
+        output::check_record(command)?;
+    }
+
+    // 기록 파일끼리 비교할 때는 kubeconfig가 필요 없고, context는 diff에서 직접 연결
+    if let Some(Command::Diff(args)) = &cli.command {
+        return output::diff::run(args, scope_config, request_config).await;
+    }
 
     let kube_config_path = dirs::home_dir()
         .map(|home| home.join(".kube/config"))
@@ -75,49 +99,122 @@ async fn main() -> Result<(), AppError> {
         return Err(AppError::IoError("Kubernetes config file is missing".to_string()));
     }
 
-    let mut terminal_setup = TerminalSetup::new()?;
+    // 모든 조회에서 공유하는 client
+    let ctx = KubeContext::new(scope_config.clone(), request_config).await?;
+    let record_interval = Duration::from_secs(cli.record_interval.max(1));
+
+    // 서브커맨드가 있거나 stdout이 터미널이 아니면(파이프, cron 등) 테이블만 출력
+    let command = cli.command.or_else(|| {
+        (!io::stdout().is_terminal()).then(|| Command::List(ListCommand::Nodes(Default::default())))
+    });
+    if let Some(command) = command {
+        // serve는 종료되지 않으므로 주기적으로 기록, 나머지는 커맨드가 조회한 데이터를 한 번 기록
+        if let (Some(path), Command::Serve(_)) = (&cli.record, &command) {
+            tokio::spawn(api::snapshot::record_loop(ctx.clone(), path.clone(), record_interval, None));
+            return output::run_command(&ctx, &command, None).await;
+        }
+        return output::run_command(&ctx, &command, cli.record.as_deref()).await;
+    }
+
     let mut app_state = AppState::new();
+    app_state.scope_config = scope_config;
+
+    if let Some(path) = cli.record {
+        let status = Arc::new(Mutex::new(RecordStatus::new(path.clone())));
+        app_state.recording = Some(status.clone());
+        tokio::spawn(api::snapshot::record_loop(ctx.clone(), path, record_interval, Some(status)));
+    }
+
+    run_ui(Some(ctx), app_state).await
+}
+
+// TUI 루프 (ctx가 없으면 replay: 현재 snapshot에서 데이터를 가져옴)
+async fn run_ui(ctx: Option<KubeContext>, mut app_state: AppState) -> Result<(), AppError> {
+    let mut terminal_setup = TerminalSetup::new()?;
     let (tx, mut rx) = mpsc::channel(100);
 
+    // RBAC 권한 확인은 UI를 띄운 뒤 백그라운드에서 (결과가 오기 전과 실패 시에는 모두 허용으로 간주)
+    let (permissions_tx, mut permissions_rx) = mpsc::channel(1);
+    if let Some(ctx) = &ctx {
+        let probe_ctx = ctx.clone();
+        tokio::spawn(async move {
+            let permissions = api::access::probe_permissions(&probe_ctx)
+                .await
+                .unwrap_or_else(|_| Permissions::all());
+            let _ = permissions_tx.send(permissions).await;
+        });
+    }
+
+    // 조회 요청마다 generation을 증가시키고, 이전 요청은 취소
+    let mut generation: u64 = 0;
+    let mut fetch_task: Option<JoinHandle<()>> = None;
+
     // 초기 데이터 로드
     app_state.is_loading = true;
-    let tx_clone = tx.clone();
-    let initial_mode = app_state.view_mode;
-    let current_sort_config = app_state.sort_config;
-    let current_search_config = app_state.search_config.clone();
-    tokio::spawn(async move {
-        if let Ok(data) = fetch_data_with_sort(initial_mode, current_search_config, current_sort_config).await {
-            let _ = tx_clone.send(data).await;
-        }
-    });
 
     loop {
+        if let Ok(permissions) = permissions_rx.try_recv() {
+            app_state.permissions = permissions;
+            // 현재 뷰를 볼 권한이 없으면 조회 중인 결과 대신 안내 표시
+            if app_state.unavailable_reason().is_some() {
+                app_state.is_loading = true;
+            }
+        }
         if app_state.is_loading {
             app_state.is_loading = false; // 플래그 해제
-            let tx_clone = tx.clone();
-            let current_mode = app_state.view_mode;
-            let current_sort_config = app_state.sort_config; // 정렬 상태 전달
-            let current_search_config = app_state.search_config.clone();
-            tokio::spawn(async move {
-                if let Ok(data) = fetch_data_with_sort(current_mode, current_search_config, current_sort_config).await {
-                    let _ = tx_clone.send(data).await;
+            generation += 1;
+            if let Some(task) = fetch_task.take() {
+                task.abort();
+            }
+
+            if app_state.unavailable_reason().is_some() {
+                // 권한이 없는 뷰는 조회하지 않음
+                app_state.is_fetching = false;
+                app_state.table = Default::default();
+                app_state.warnings.clear();
+                app_state.summary = None;
+            } else if let Some(replay) = &app_state.replay {
+                let snapshot = replay.current();
+                let time = snapshot.time;
+                if let Some(dataset) = snapshot.dataset(app_state.view_mode) {
+                    app_state.apply_fetch_result(FetchOutcome {
+                        result: Ok((dataset, Vec::new())),
+                        latency: Duration::ZERO,
+                        retries: 0,
+                    });
+                    // 갱신 시각 대신 기록 시각 표시
+                    app_state.last_refresh = Some(time);
+                    app_state.scroll_offset = 0;
                 }
-            });
+            } else if let Some(ctx) = &ctx {
+                let tx_clone = tx.clone();
+                let fetch_ctx = ctx.for_fetch();
+                let current_generation = generation;
+                let current_mode = app_state.view_mode;
+                app_state.is_fetching = true;
+                app_state.progress = fetch_ctx.progress.clone();
+                fetch_task = Some(tokio::spawn(async move {
+                    let outcome = fetch_with_stats(fetch_ctx, current_mode).await;
+                    let _ = tx_clone.send((current_generation, outcome)).await;
+                }));
+            }
         }
-        // 데이터 업데이트 처리
-        if let Ok(data) = rx.try_recv() {
-            app_state.rows = data;
-            app_state.is_loading = false;
-            app_state.scroll_offset = 0; // 스크롤 초기화
+        // 데이터 업데이트 처리 (이전 generation의 결과는 무시)
+        while let Ok((result_generation, result)) = rx.try_recv() {
+            if result_generation == generation {
+                app_state.apply_fetch_result(result);
+                app_state.scroll_offset = 0; // 스크롤 초기화
+            }
         }
 
         terminal_setup.terminal.draw(|f| draw_ui(f, &mut app_state))?;
 
         // 이벤트 처리
         if crossterm::event::poll(Duration::from_millis(100))? {
-            if let Ok(data) = rx.try_recv() {
-                app_state.rows = data;
-                app_state.is_loading = false;
+            if let Ok((result_generation, result)) = rx.try_recv() {
+                if result_generation == generation {
+                    app_state.apply_fetch_result(result);
+                }
             }
             let event = crossterm::event::read()?;
             if !handle_event(event, &mut app_state) {
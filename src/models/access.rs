@@ -0,0 +1,54 @@
+use crate::models::app::ViewMode;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Permissions {
+    pub nodes: bool,
+    pub pods: bool,
+    pub workloads: bool, // Deployment, StatefulSet, ReplicaSet
+    pub hpas: bool,
+    pub pvcs: bool,
+}
+
+impl Permissions {
+    // 권한 확인이 불가능한 경우 기존 동작 유지를 위해 모두 허용으로 간주
+    pub fn all() -> Self {
+        Self {
+            nodes: true,
+            pods: true,
+            workloads: true,
+            hpas: true,
+            pvcs: true,
+        }
+    }
+
+    // 해당 뷰를 표시하기 위해 반드시 필요한데 조회 권한이 없는 리소스 목록
+    pub fn missing_for(&self, view_mode: ViewMode) -> Vec<&'static str> {
+        let required: Vec<(bool, &'static str)> = match view_mode {
+            ViewMode::Node | ViewMode::Pod | ViewMode::Namespace | ViewMode::Priority => {
+                vec![(self.pods, "pods")]
+            }
+            ViewMode::Hpa => vec![
+                (self.hpas, "horizontalpodautoscalers"),
+                (self.pods, "pods"),
+                (self.workloads, "deployments/statefulsets/replicasets"),
+            ],
+            ViewMode::Storage | ViewMode::StorageClass | ViewMode::Pvc => {
+                vec![(self.pvcs, "persistentvolumeclaims")]
+            }
+        };
+
+        required
+            .into_iter()
+            .filter(|(allowed, _)| !allowed)
+            .map(|(_, resource)| resource)
+            .collect()
+    }
+
+    // 뷰는 표시할 수 있지만 권한이 없어 비워지는 컬럼 인덱스
+    pub fn hidden_columns(&self, view_mode: ViewMode) -> Vec<usize> {
+        match view_mode {
+            ViewMode::Node if !self.nodes => vec![1, 2], // CPU/Memory Alloc.
+            _ => Vec::new(),
+        }
+    }
+}
@@ -1,19 +1,87 @@
-use tui::layout::Constraint;
-use crate::models::config::{SortConfig, SearchConfig};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use chrono::{DateTime, Local};
+use crate::api::dataset::Dataset;
+use crate::api::snapshot::{RecordStatus, Replay};
+use crate::models::access::Permissions;
+use crate::models::error::AppError;
+use crate::models::progress::FetchProgress;
+use crate::models::search::TextSearch;
+use crate::models::table::Table;
+use crate::models::warning::{ParseWarning, WARNING_COLUMNS};
+use crate::models::config::{ScopeConfig, SortConfig, SearchConfig};
 
-#[derive(Debug, Clone, Copy)]
+// 조회 결과: (뷰 데이터, 파싱할 수 없었던 quantity 값)
+pub type FetchResult = Result<(Dataset, Vec<ParseWarning>), AppError>;
+
+// 조회 결과와 API 통계 (소요 시간, 재시도 횟수)
+pub struct FetchOutcome {
+    pub result: FetchResult,
+    pub latency: Duration,
+    pub retries: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConnectionHealth {
+    Unknown,
+    Connected,    // 마지막 조회 성공
+    Degraded,     // 재시도 후 성공
+    Disconnected, // 마지막 조회 실패
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ViewMode {
     Node,
     Pod,
     Namespace,
+    Priority,
+    Hpa,
+    Storage,
+    StorageClass,
+    Pvc,
+}
+
+impl ViewMode {
+    // 서브커맨드, 내보내기 파일 이름, JSON/YAML의 kind
+    pub fn name(&self) -> &'static str {
+        match self {
+            ViewMode::Node => "nodes",
+            ViewMode::Pod => "pods",
+            ViewMode::Namespace => "namespaces",
+            ViewMode::Priority => "priorities",
+            ViewMode::Hpa => "hpas",
+            ViewMode::Storage => "storage",
+            ViewMode::StorageClass => "storage-classes",
+            ViewMode::Pvc => "pvcs",
+        }
+    }
 }
 
 pub struct AppState {
-    pub rows: Vec<Vec<String>>,
+    pub dataset: Option<Dataset>,
+    pub table: Table,
+    pub summary: Option<String>,
     pub is_loading: bool,
+    pub is_fetching: bool,
+    pub progress: Arc<FetchProgress>, // 진행 중인 조회의 페이지 진행 상황
+    pub last_refresh: Option<DateTime<Local>>,
+    pub last_error: Option<AppError>,
+    pub show_error: bool,
+    pub warnings: Vec<ParseWarning>, // 마지막 조회에서 파싱할 수 없었던 quantity 값
+    pub show_warnings: bool,         // 현재 뷰 대신 경고 목록 표시
+    pub export_prompt: bool,         // 내보내기 형식 선택 중
+    pub last_export: Option<Result<PathBuf, AppError>>, // 마지막 내보내기 결과 (파일 경로)
+    pub replay: Option<Replay>,                          // --replay: 클러스터 대신 기록된 snapshot 표시
+    pub recording: Option<Arc<Mutex<RecordStatus>>>,     // --record 진행 상황
     pub view_mode: ViewMode,
     pub sort_config: SortConfig,
     pub search_config: SearchConfig,
+    pub text_search: TextSearch,     // '/' 검색 (모든 텍스트 컬럼)
+    pub scope_config: ScopeConfig,
+    pub permissions: Permissions,
+    pub health: ConnectionHealth,
+    pub api_latency: Option<Duration>,
     pub scroll_offset_horizontal: usize,
     pub scroll_offset: usize,
     pub visible_height: usize,
@@ -23,11 +91,29 @@ pub struct AppState {
 impl AppState {
     pub fn new() -> Self {
         Self {
-            rows: Vec::new(),
+            dataset: None,
+            table: Table::default(),
+            summary: None,
             is_loading: false,
+            is_fetching: false,
+            progress: Arc::new(FetchProgress::new()),
+            last_refresh: None,
+            last_error: None,
+            show_error: false,
+            warnings: Vec::new(),
+            show_warnings: false,
+            export_prompt: false,
+            last_export: None,
+            replay: None,
+            recording: None,
             view_mode: ViewMode::Node,
             sort_config: SortConfig::new(0),
             search_config: SearchConfig::new(999, ""),
+            text_search: TextSearch::default(),
+            scope_config: ScopeConfig::default(),
+            permissions: Permissions::all(),
+            health: ConnectionHealth::Unknown,
+            api_latency: None,
             scroll_offset_horizontal: 0,
             scroll_offset: 0,
             visible_height: 0,
@@ -35,24 +121,20 @@ impl AppState {
         }
     }
 
-    pub fn get_widths(&self) -> Vec<Constraint> {
+    pub fn get_widths(&self) -> Vec<u16> {
         // 터미널 너비와 여백 설정
         let terminal_width = self.get_table_area_width() as usize;
         let horizontal_padding = 4; // 고정된 좌우 여백
         let available_width = terminal_width.saturating_sub(horizontal_padding);
     
-        // 현재 ViewMode에 따른 기본 컬럼 너비
-        let mut widths = match self.view_mode {
-            ViewMode::Node => vec![20, 15, 15, 15, 15, 15, 15], // Node
-            ViewMode::Pod => vec![20, 35, 15, 25, 15, 15, 15, 15], // Pod
-            ViewMode::Namespace => vec![30, 15, 15, 15, 15], // Namespace
-        };
+        // 현재 테이블의 컬럼 정의에 있는 기본 컬럼 너비
+        let mut widths: Vec<usize> = self.table.widths.iter().map(|&w| w as usize).collect();
     
         // 모든 컬럼 너비의 합 계산
         let total_width: usize = widths.iter().sum();
     
         // 필요할 경우 너비 조정
-        if available_width < total_width {
+        if available_width < total_width && !widths.is_empty() {
             let excess = total_width.saturating_sub(available_width);
     
             // 각 컬럼의 너비를 균등하게 줄임 (최소 5를 유지)
@@ -65,8 +147,7 @@ impl AppState {
             });
         }
     
-        // `Constraint::Length`로 변환하여 반환
-        widths.iter().map(|&w| Constraint::Length(w as u16)).collect()
+        widths.iter().map(|&w| w as u16).collect()
     }
 
     fn get_table_area_width(&self) -> u16 {
@@ -74,11 +155,84 @@ impl AppState {
         terminal_size.0 // 가로 크기 반환
     }
 
+    pub fn apply_fetch_result(&mut self, outcome: FetchOutcome) {
+        self.is_fetching = false;
+        self.api_latency = Some(outcome.latency);
+        self.health = match (&outcome.result, outcome.retries) {
+            (Ok(_), 0) => ConnectionHealth::Connected,
+            (Ok(_), _) => ConnectionHealth::Degraded,
+            (Err(_), _) => ConnectionHealth::Disconnected,
+        };
+        match outcome.result {
+            Ok((dataset, warnings)) => {
+                self.dataset = Some(dataset);
+                self.warnings = warnings;
+                self.rebuild_rows();
+                self.last_refresh = Some(Local::now());
+                self.last_error = None;
+                self.show_error = false;
+            }
+            // 에러 시 기존 데이터는 유지
+            Err(e) => self.last_error = Some(e),
+        }
+    }
+
+    // 마지막 조회 데이터에 현재 정렬/필터를 적용하여 표시용 행 재계산
+    pub fn rebuild_rows(&mut self) {
+        if self.show_warnings {
+            self.table = Table::build(&self.warnings, WARNING_COLUMNS, None, Some(self.sort_config));
+            self.summary = None;
+        } else if let Some(dataset) = &self.dataset {
+            if dataset.view_mode() == self.view_mode {
+                self.table = dataset.to_table(self.search_config, self.sort_config);
+                self.summary = dataset.summary();
+            }
+        }
+        let text_search = &self.text_search;
+        self.table.retain_rows(|row| text_search.matches_row(row));
+    }
+
+    // 검색어가 바뀌면 첫 행부터 다시 표시
+    pub fn update_search(&mut self) {
+        self.selected_row = 0;
+        self.scroll_offset = 0;
+        self.rebuild_rows();
+    }
+
+    // 현재 뷰에 필요한 조회 권한이 없으면 그 이유를 반환
+    pub fn unavailable_reason(&self) -> Option<String> {
+        if self.replay.is_some() {
+            // 기록 파일에는 Node/Pod/Namespace만 있음
+            let recorded = matches!(self.view_mode, ViewMode::Node | ViewMode::Pod | ViewMode::Namespace);
+            return (!recorded).then(|| "Not available in replay: only nodes, pods and namespaces are recorded".to_string());
+        }
+        let missing = self.permissions.missing_for(self.view_mode);
+        if missing.is_empty() {
+            None
+        } else {
+            Some(format!("Disabled: no permission to list {}", missing.join(", ")))
+        }
+    }
+
+    // 경고 목록과 현재 뷰 사이 전환 (정렬 컬럼은 뷰마다 다르므로 초기화)
+    pub fn toggle_warnings(&mut self) {
+        self.show_warnings = !self.show_warnings;
+        self.sort_config.column = 0;
+        self.selected_row = 0;
+        self.scroll_offset = 0;
+        self.rebuild_rows();
+    }
+
     pub fn toggle_view_mode(&mut self) {
         self.view_mode = match self.view_mode {
             ViewMode::Node => ViewMode::Pod,
             ViewMode::Pod => ViewMode::Namespace,
-            ViewMode::Namespace => ViewMode::Node,
+            ViewMode::Namespace => ViewMode::Priority,
+            ViewMode::Priority => ViewMode::Hpa,
+            ViewMode::Hpa => ViewMode::Storage,
+            ViewMode::Storage => ViewMode::StorageClass,
+            ViewMode::StorageClass => ViewMode::Pvc,
+            ViewMode::Pvc => ViewMode::Node,
         };
     }
 }
\ No newline at end of file
@@ -0,0 +1,222 @@
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+use crate::models::app::ViewMode;
+
+#[derive(Parser, Debug)]
+#[command(version, about = "Terminal UI for Kubernetes cluster resources (CPU, Memory)")]
+pub struct Cli {
+    /// Print a table to stdout instead of starting the UI (the default when stdout is not a terminal)
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Only query these namespaces (comma separated), for users without cluster-wide list permission
+    #[arg(short = 'n', long = "namespace", value_delimiter = ',', global = true)]
+    pub namespaces: Vec<String>,
+
+    /// Timeout in seconds for each API request
+    #[arg(long, default_value_t = 10, global = true)]
+    pub timeout: u64,
+
+    /// Number of retries with exponential backoff for transient API errors
+    #[arg(long, default_value_t = 3, global = true)]
+    pub retries: u32,
+
+    /// Number of items per page when listing resources (limit/continue)
+    #[arg(long, default_value_t = 500, global = true)]
+    pub page_size: u32,
+
+    /// Append a timestamped snapshot of node, pod and namespace state to this file (JSON lines)
+    #[arg(long, value_name = "FILE", global = true)]
+    pub record: Option<PathBuf>,
+
+    /// Seconds between recorded snapshots in the UI and in serve mode
+    #[arg(long, default_value_t = 60, global = true)]
+    pub record_interval: u64,
+
+    /// Open the UI against snapshots written by --record, without cluster access
+    #[arg(long, value_name = "FILE", conflicts_with = "record")]
+    pub replay: Option<PathBuf>,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum Command {
+    #[command(flatten)]
+    List(ListCommand),
+    /// Write a Markdown or HTML capacity report for ops reviews
+    Report(ReportArgs),
+    /// Run headless and expose node/namespace gauges on /metrics in Prometheus format
+    Serve(ServeArgs),
+    /// Compare requests, limits and allocatable between two recordings or kube contexts
+    Diff(DiffArgs),
+    /// Check capacity against a policy file; exits with 2 when a rule is violated
+    Check(CheckArgs),
+}
+
+// 뷰와 같은 테이블을 출력하는 서브커맨드
+#[derive(Subcommand, Debug, Clone)]
+pub enum ListCommand {
+    /// Print requests, limits and allocatable per node
+    Nodes(ListArgs),
+    /// Print requests and limits per pod
+    Pods(PodsArgs),
+    /// Print requests and limits per namespace
+    Namespaces(ListArgs),
+    /// Print requested capacity per node and priority band
+    Priorities(ListArgs),
+    /// Print HPA replicas and requests at current and max replicas
+    Hpas(ListArgs),
+    /// Print PVC counts and capacity per namespace
+    Storage(ListArgs),
+    /// Print PVC counts and capacity per storage class
+    StorageClasses(ListArgs),
+    /// Print requested and bound capacity per PVC
+    Pvcs(ListArgs),
+}
+
+#[derive(Args, Debug, Clone, Default)]
+pub struct ListArgs {
+    /// Sort by column, given as header name (e.g. "cpu-req", "Mem Lim.") or 0-based index
+    #[arg(long, value_name = "COLUMN")]
+    pub sort: Option<String>,
+
+    /// Only print rows whose column equals the value (e.g. "node=worker-1")
+    #[arg(long, value_name = "COLUMN=VALUE")]
+    pub filter: Option<String>,
+
+    /// Output format
+    #[arg(short = 'o', long = "output", value_enum, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+
+    /// Units for CPU and memory values in csv/tsv output
+    #[arg(long, value_enum, default_value_t = Units::Raw)]
+    pub units: Units,
+
+    /// Leave out the TOTAL row in csv/tsv output
+    #[arg(long)]
+    pub no_total: bool,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq)]
+pub enum OutputFormat {
+    /// Plain text table, same columns as the UI
+    #[default]
+    Table,
+    /// JSON records with raw values (millicores, bytes) and formatted strings
+    Json,
+    /// YAML records with raw values (millicores, bytes) and formatted strings
+    Yaml,
+    /// Comma separated values for spreadsheets
+    Csv,
+    /// Tab separated values for spreadsheets
+    Tsv,
+    /// One JSON event per line (ADDED/MODIFIED/DELETED rows with resource deltas)
+    Ndjson,
+}
+
+#[derive(Args, Debug, Clone, Default)]
+pub struct PodsArgs {
+    #[command(flatten)]
+    pub list: ListArgs,
+
+    /// Keep watching pods and reprint the table (or emit ndjson events) on every change
+    #[arg(short = 'w', long)]
+    pub watch: bool,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct ReportArgs {
+    /// Document format
+    #[arg(long, value_enum, default_value_t = ReportFormat::Markdown)]
+    pub format: ReportFormat,
+
+    /// Number of namespaces to list in the top namespaces sections
+    #[arg(long, default_value_t = 10)]
+    pub top: usize,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct ServeArgs {
+    /// Address to listen on (":9100" listens on all interfaces)
+    #[arg(long, default_value = ":9100")]
+    pub listen: String,
+
+    /// Seconds between fetches from the API server
+    #[arg(long, default_value_t = 60)]
+    pub interval: u64,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct DiffArgs {
+    /// Baseline: a --record file (FILE for its last snapshot, FILE@N for the N-th, negative N counts from the end) or a kube context
+    pub before: String,
+
+    /// State to compare against the baseline, in the same form
+    pub after: String,
+
+    /// Resource whose change orders the rows (largest change first)
+    #[arg(long, value_enum, default_value_t = DiffSort::Cpu)]
+    pub sort: DiffSort,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct CheckArgs {
+    /// Policy file (TOML) with node, namespace, pod and cluster rules
+    #[arg(long, value_name = "FILE")]
+    pub policy: PathBuf,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq)]
+pub enum DiffSort {
+    /// CPU requests, limits and allocatable
+    #[default]
+    Cpu,
+    /// Memory requests, limits and allocatable
+    Memory,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq)]
+pub enum ReportFormat {
+    /// Markdown, for pasting into wikis and issues
+    #[default]
+    Markdown,
+    /// Self-contained HTML page with inline styles
+    Html,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq)]
+pub enum Units {
+    /// CPU in millicores, memory in bytes
+    #[default]
+    Raw,
+    /// CPU in cores, memory in GiB
+    CoresGib,
+}
+
+impl ListCommand {
+    pub fn view_mode(&self) -> ViewMode {
+        match self {
+            ListCommand::Nodes(_) => ViewMode::Node,
+            ListCommand::Pods(_) => ViewMode::Pod,
+            ListCommand::Namespaces(_) => ViewMode::Namespace,
+            ListCommand::Priorities(_) => ViewMode::Priority,
+            ListCommand::Hpas(_) => ViewMode::Hpa,
+            ListCommand::Storage(_) => ViewMode::Storage,
+            ListCommand::StorageClasses(_) => ViewMode::StorageClass,
+            ListCommand::Pvcs(_) => ViewMode::Pvc,
+        }
+    }
+
+    pub fn list_args(&self) -> &ListArgs {
+        match self {
+            ListCommand::Pods(args) => &args.list,
+            ListCommand::Nodes(args)
+            | ListCommand::Namespaces(args)
+            | ListCommand::Priorities(args)
+            | ListCommand::Hpas(args)
+            | ListCommand::Storage(args)
+            | ListCommand::StorageClasses(args)
+            | ListCommand::Pvcs(args) => args,
+        }
+    }
+}
@@ -1,3 +1,14 @@
+use std::time::Duration;
+
+// max 바이트 이하가 되도록 글자 경계에서 자른 길이
+fn truncated_len(word: &str, max: usize) -> usize {
+    let mut len = word.len().min(max);
+    while !word.is_char_boundary(len) {
+        len -= 1;
+    }
+    len
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct SearchConfig {
     pub column: usize,
@@ -9,8 +20,8 @@ impl SearchConfig {
         let mut word_array = [0u8; 64]; // 고정 크기 배열 초기화
         let bytes = word.as_bytes();
 
-        // word 길이가 배열 크기를 초과하면 잘라냄
-        let len = bytes.len().min(64);
+        // word 길이가 배열 크기를 초과하면 잘라냄 (UTF-8 글자 중간에서 자르지 않음)
+        let len = truncated_len(word, 64);
         word_array[..len].copy_from_slice(&bytes[..len]);
 
         Self {
@@ -31,7 +42,7 @@ impl SearchConfig {
         let bytes = new_word.as_bytes();
 
         // 새 문자열을 복사
-        let len = bytes.len().min(64);
+        let len = truncated_len(new_word, 64);
         self.word[..len].copy_from_slice(&bytes[..len]);
     }
 }
@@ -47,4 +58,42 @@ impl SortConfig {
             column,
         }
     }
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ScopeConfig {
+    pub namespaces: Vec<String>, // 비어 있으면 클러스터 전체 조회
+}
+
+impl ScopeConfig {
+    pub fn new(namespaces: Vec<String>) -> Self {
+        Self {
+            namespaces: namespaces
+                .into_iter()
+                .map(|ns| ns.trim().to_string())
+                .filter(|ns| !ns.is_empty())
+                .collect(),
+        }
+    }
+
+    pub fn is_cluster_wide(&self) -> bool {
+        self.namespaces.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RequestConfig {
+    pub timeout: Duration, // API 요청 1회당 timeout
+    pub retries: u32,      // 일시적인 에러 발생 시 최대 재시도 횟수
+    pub page_size: u32,    // 목록 조회 시 페이지당 항목 수 (limit)
+}
+
+impl RequestConfig {
+    pub fn new(timeout_secs: u64, retries: u32, page_size: u32) -> Self {
+        Self {
+            timeout: Duration::from_secs(timeout_secs.max(1)),
+            retries,
+            page_size: page_size.max(1),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 
 use std::io;
 
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug)]
 pub enum AppError {
     IoError(String),
@@ -8,6 +9,16 @@ pub enum AppError {
     ParseError(String),
 }
 
+impl AppError {
+    pub fn variant_name(&self) -> &'static str {
+        match self {
+            AppError::IoError(_) => "IoError",
+            AppError::KubeError(_) => "KubeError",
+            AppError::ParseError(_) => "ParseError",
+        }
+    }
+}
+
 impl std::error::Error for AppError {}
 
 impl std::fmt::Display for AppError {
@@ -1,4 +1,11 @@
 pub mod error;
 pub mod app;
 pub mod config;
-pub mod resource;
\ No newline at end of file
+pub mod resource;
+pub mod search;
+pub mod cli;
+pub mod policy;
+pub mod access;
+pub mod progress;
+pub mod table;
+pub mod warning;
@@ -0,0 +1,80 @@
+use serde::Deserialize;
+
+// check 커맨드의 정책 파일 (TOML)
+// 설정하지 않은 규칙은 검사하지 않음, 오타는 에러로 처리
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Policy {
+    pub nodes: NodePolicy,
+    pub namespaces: NamespacePolicy,
+    pub pods: PodPolicy,
+    pub cluster: ClusterPolicy,
+}
+
+// 노드별 request / allocatable 비율 상한 (CPU/메모리 공통 값과 개별 값)
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NodePolicy {
+    pub max_request_percent: Option<f64>,
+    pub max_cpu_request_percent: Option<f64>,
+    pub max_memory_request_percent: Option<f64>,
+}
+
+// namespace request가 클러스터 allocatable에서 차지하는 비율 상한
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NamespacePolicy {
+    pub max_share_percent: Option<f64>,
+    pub max_cpu_share_percent: Option<f64>,
+    pub max_memory_share_percent: Option<f64>,
+}
+
+// request/limit이 없는 Pod (완료된 Pod와 제외 namespace는 검사하지 않음)
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PodPolicy {
+    pub require_requests: bool,
+    pub require_limits: bool,
+    pub exclude_namespaces: Vec<String>,
+}
+
+// 클러스터 여유 용량 (allocatable - request) 하한, Kubernetes quantity ("8", "32Gi")
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ClusterPolicy {
+    pub min_free_cpu: Option<String>,
+    pub min_free_memory: Option<String>,
+}
+
+impl NodePolicy {
+    pub fn max_cpu(&self) -> Option<f64> {
+        self.max_cpu_request_percent.or(self.max_request_percent)
+    }
+
+    pub fn max_memory(&self) -> Option<f64> {
+        self.max_memory_request_percent.or(self.max_request_percent)
+    }
+}
+
+impl NamespacePolicy {
+    pub fn max_cpu(&self) -> Option<f64> {
+        self.max_cpu_share_percent.or(self.max_share_percent)
+    }
+
+    pub fn max_memory(&self) -> Option<f64> {
+        self.max_memory_share_percent.or(self.max_share_percent)
+    }
+}
+
+impl Policy {
+    pub fn is_empty(&self) -> bool {
+        self.nodes.max_cpu().is_none()
+            && self.nodes.max_memory().is_none()
+            && self.namespaces.max_cpu().is_none()
+            && self.namespaces.max_memory().is_none()
+            && !self.pods.require_requests
+            && !self.pods.require_limits
+            && self.cluster.min_free_cpu.is_none()
+            && self.cluster.min_free_memory.is_none()
+    }
+}
@@ -0,0 +1,44 @@
+use std::sync::Mutex;
+
+// 페이지 단위 목록 조회 진행 상황 (status bar 표시용)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProgressSnapshot {
+    pub kind: String,             // 조회 중인 리소스 (예: pods)
+    pub loaded: usize,            // 지금까지 받은 항목 수
+    pub estimated: Option<usize>, // remainingItemCount 기반 전체 추정치
+}
+
+// 조회 task와 UI가 공유하는 진행 상황
+#[derive(Debug, Default)]
+pub struct FetchProgress {
+    state: Mutex<ProgressSnapshot>,
+}
+
+impl FetchProgress {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 새 리소스 목록 조회 시작
+    pub fn start(&self, kind: &str) {
+        if let Ok(mut state) = self.state.lock() {
+            *state = ProgressSnapshot {
+                kind: kind.to_string(),
+                loaded: 0,
+                estimated: None,
+            };
+        }
+    }
+
+    // 한 페이지를 받은 뒤 호출 (remaining: 서버가 알려준 남은 항목 수)
+    pub fn advance(&self, count: usize, remaining: Option<i64>) {
+        if let Ok(mut state) = self.state.lock() {
+            state.loaded += count;
+            state.estimated = remaining.map(|r| state.loaded + r.max(0) as usize);
+        }
+    }
+
+    pub fn snapshot(&self) -> ProgressSnapshot {
+        self.state.lock().map(|s| s.clone()).unwrap_or_default()
+    }
+}
@@ -1,87 +1,198 @@
 use std::collections::BTreeMap;
 
 use crate::util::common::{extract_quantity, parse_cpu, parse_memory};
-use k8s_openapi::api::core::v1::ResourceRequirements;
+use crate::models::warning::{ParseWarning, WarningSource};
+use k8s_openapi::api::core::v1::{PodSpec, ResourceRequirements};
 use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, Default)]  // Default trait 추가
-pub struct ResourceValue(pub i64);      // field를 pub으로 변경
+// CPU 양 (nanocore 단위, 1 core = 1,000,000,000n)
+// millicore 미만의 값(100u, 2500000n 등)도 손실 없이 합산
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Cpu(i64);
 
-impl ResourceValue {
-    pub fn new(value: i64) -> Self {
-        Self(value)
+// 메모리/스토리지 양 (byte 단위)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Memory(i64);
+
+const NANOCORES_PER_MILLICORE: i64 = 1_000_000;
+
+impl Cpu {
+    pub const fn from_nanocores(nanocores: i64) -> Self {
+        Self(nanocores)
     }
 
-    pub fn as_millicores(&self) -> i64 {
+    pub const fn as_nanocores(&self) -> i64 {
         self.0
     }
 
-    pub fn as_bytes(&self) -> i64 {
+    // millicore 미만은 올림 (Kubernetes MilliValue와 동일)
+    // 부호를 뒤집지 않으므로 i64::MIN(saturate된 음수 delta)에서도 overflow 없음
+    pub fn as_millicores(&self) -> i64 {
+        self.0.div_euclid(NANOCORES_PER_MILLICORE) + i64::from(self.0.rem_euclid(NANOCORES_PER_MILLICORE) != 0)
+    }
+}
+
+impl Memory {
+    pub const fn from_bytes(bytes: i64) -> Self {
+        Self(bytes)
+    }
+
+    pub const fn as_bytes(&self) -> i64 {
         self.0
     }
 }
 
-#[derive(Default, Clone)]
+// 덧셈/뺄셈/replica 수 곱셈은 overflow 시 i64 범위로 saturate
+macro_rules! impl_quantity_ops {
+    ($quantity:ty) => {
+        impl std::ops::Add for $quantity {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self(self.0.saturating_add(other.0))
+            }
+        }
+
+        impl std::ops::Sub for $quantity {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self(self.0.saturating_sub(other.0))
+            }
+        }
+
+        impl std::ops::Mul<i64> for $quantity {
+            type Output = Self;
+
+            fn mul(self, factor: i64) -> Self {
+                Self(self.0.saturating_mul(factor))
+            }
+        }
+
+        impl std::ops::AddAssign for $quantity {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl std::ops::SubAssign for $quantity {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl std::iter::Sum for $quantity {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::default(), |acc, value| acc + value)
+            }
+        }
+    };
+}
+
+impl_quantity_ops!(Cpu);
+impl_quantity_ops!(Memory);
+
+#[derive(Default, Clone, Serialize, Deserialize)]
 pub struct Resources {
-    pub cpu_request: ResourceValue,
-    pub cpu_limit: ResourceValue,
-    pub memory_request: ResourceValue,
-    pub memory_limit: ResourceValue,
+    pub cpu_request: Cpu,
+    pub cpu_limit: Cpu,
+    pub memory_request: Memory,
+    pub memory_limit: Memory,
 }
 
-#[derive(Default, Clone)]
+#[derive(Default, Clone, Serialize, Deserialize)]
 pub struct NodeResources {
-    pub base: Resources,                // 기본 리소스 정보
-    pub allocatable_cpu: ResourceValue, // 노드에서 사용 가능한 CPU
-    pub allocatable_memory: ResourceValue, // 노드에서 사용 가능한 메모리
+    pub base: Resources,            // 기본 리소스 정보
+    pub allocatable_cpu: Cpu,       // 노드에서 사용 가능한 CPU
+    pub allocatable_memory: Memory, // 노드에서 사용 가능한 메모리
 }
 
 impl Resources {
     pub fn new() -> Self {
-        Self {
-            cpu_request: ResourceValue::new(0),
-            cpu_limit: ResourceValue::new(0),
-            memory_request: ResourceValue::new(0),
-            memory_limit: ResourceValue::new(0),
-        }
+        Self::default()
     }
 
-    pub fn add_container_resources(&mut self, resources: &ResourceRequirements) {
-        let cpu_req = extract_quantity(&resources.requests, "cpu", parse_cpu);
-        let cpu_lim = extract_quantity(&resources.limits, "cpu", parse_cpu);
-        let mem_req = extract_quantity(&resources.requests, "memory", parse_memory);
-        let mem_lim = extract_quantity(&resources.limits, "memory", parse_memory);
+    pub fn add_container_resources(
+        &mut self,
+        resources: &ResourceRequirements,
+        source: &WarningSource,
+        warnings: &mut Vec<ParseWarning>,
+    ) {
+        self.cpu_request += extract_quantity(&resources.requests, "requests", "cpu", parse_cpu, source, warnings);
+        self.cpu_limit += extract_quantity(&resources.limits, "limits", "cpu", parse_cpu, source, warnings);
+        self.memory_request += extract_quantity(&resources.requests, "requests", "memory", parse_memory, source, warnings);
+        self.memory_limit += extract_quantity(&resources.limits, "limits", "memory", parse_memory, source, warnings);
+    }
 
-        self.cpu_request = ResourceValue::new(self.cpu_request.as_millicores() + cpu_req.as_millicores());
-        self.cpu_limit = ResourceValue::new(self.cpu_limit.as_millicores() + cpu_lim.as_millicores());
-        self.memory_request = ResourceValue::new(self.memory_request.as_bytes() + mem_req.as_bytes());
-        self.memory_limit = ResourceValue::new(self.memory_limit.as_bytes() + mem_lim.as_bytes());
+    pub fn add_pod_spec(&mut self, spec: &PodSpec, source: &WarningSource, warnings: &mut Vec<ParseWarning>) {
+        for container in &spec.containers {
+            if let Some(container_resources) = &container.resources {
+                self.add_container_resources(container_resources, &source.with_container(&container.name), warnings);
+            }
+        }
     }
 
     pub fn add(&mut self, other: &Resources) {
-        self.cpu_request = ResourceValue::new(self.cpu_request.as_millicores() + other.cpu_request.as_millicores());
-        self.cpu_limit = ResourceValue::new(self.cpu_limit.as_millicores() + other.cpu_limit.as_millicores());
-        self.memory_request = ResourceValue::new(self.memory_request.as_bytes() + other.memory_request.as_bytes());
-        self.memory_limit = ResourceValue::new(self.memory_limit.as_bytes() + other.memory_limit.as_bytes());
+        self.cpu_request += other.cpu_request;
+        self.cpu_limit += other.cpu_limit;
+        self.memory_request += other.memory_request;
+        self.memory_limit += other.memory_limit;
+    }
+
+    pub fn sub(&mut self, other: &Resources) {
+        self.cpu_request -= other.cpu_request;
+        self.cpu_limit -= other.cpu_limit;
+        self.memory_request -= other.memory_request;
+        self.memory_limit -= other.memory_limit;
+    }
+
+    // replica 수만큼 곱한 리소스 (HPA 예측용)
+    pub fn scaled(&self, replicas: i64) -> Resources {
+        Resources {
+            cpu_request: self.cpu_request * replicas,
+            cpu_limit: self.cpu_limit * replicas,
+            memory_request: self.memory_request * replicas,
+            memory_limit: self.memory_limit * replicas,
+        }
     }
 }
 
 impl NodeResources {
     pub fn new() -> Self {
-        Self {
-            base: Resources::new(),
-            allocatable_cpu: ResourceValue::new(0),
-            allocatable_memory: ResourceValue::new(0),
-        }
+        Self::default()
     }
 
-    pub fn add_container_resources(&mut self, resources: &ResourceRequirements) {
-        self.base.add_container_resources(resources);
+    pub fn add_allocatable(
+        &mut self,
+        allocatable: &BTreeMap<String, Quantity>,
+        source: &WarningSource,
+        warnings: &mut Vec<ParseWarning>,
+    ) {
+        let allocatable_ref = Some(allocatable.clone());
+        self.allocatable_cpu = extract_quantity(&allocatable_ref, "allocatable", "cpu", parse_cpu, source, warnings);
+        self.allocatable_memory = extract_quantity(&allocatable_ref, "allocatable", "memory", parse_memory, source, warnings);
     }
+}
 
-    pub fn add_allocatable(&mut self, allocatable: &BTreeMap<String, Quantity>) {
-        let allocatable_ref = Some(allocatable.clone());
-        self.allocatable_cpu = extract_quantity(&allocatable_ref, "cpu", parse_cpu);
-        self.allocatable_memory = extract_quantity(&allocatable_ref, "memory", parse_memory);
+#[derive(Default, Clone)]
+pub struct StorageResources {
+    pub claims: i64,        // PVC 개수
+    pub bound: i64,         // Bound 상태 PVC 개수
+    pub pending: i64,       // Pending 상태 PVC 개수
+    pub requested: Memory,  // spec.resources.requests.storage 합계
+    pub capacity: Memory,   // status.capacity.storage 합계
+}
+
+impl StorageResources {
+    pub fn add_claim(&mut self, phase: &str, requested: Memory, capacity: Memory) {
+        self.claims += 1;
+        match phase {
+            "Bound" => self.bound += 1,
+            "Pending" => self.pending += 1,
+            _ => {}
+        }
+        self.requested += requested;
+        self.capacity += capacity;
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,187 @@
+use regex::Regex;
+use std::ops::Range;
+
+use crate::models::table::CellValue;
+use crate::util::common::fuzzy_match;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum SearchMode {
+    #[default]
+    Substring, // 대소문자 구분 없음
+    Regex,
+    Fuzzy,     // 글자가 순서대로 나오면 일치
+}
+
+impl SearchMode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SearchMode::Substring => "substring",
+            SearchMode::Regex => "regex",
+            SearchMode::Fuzzy => "fuzzy",
+        }
+    }
+
+    fn next(&self) -> Self {
+        match self {
+            SearchMode::Substring => SearchMode::Regex,
+            SearchMode::Regex => SearchMode::Fuzzy,
+            SearchMode::Fuzzy => SearchMode::Substring,
+        }
+    }
+}
+
+// '/'로 입력하는 검색어: 현재 뷰의 모든 텍스트 컬럼에서 찾음
+#[derive(Debug, Clone, Default)]
+pub struct TextSearch {
+    pub query: String,
+    pub mode: SearchMode,
+    pub editing: bool,         // 검색어 입력 중
+    pub error: Option<String>, // 잘못된 정규식 (검색어가 고쳐질 때까지 필터하지 않음)
+    pattern: Option<Regex>,
+}
+
+impl TextSearch {
+    pub fn push(&mut self, c: char) {
+        self.query.push(c);
+        self.compile();
+    }
+
+    pub fn pop(&mut self) {
+        self.query.pop();
+        self.compile();
+    }
+
+    pub fn cycle_mode(&mut self) {
+        self.mode = self.mode.next();
+        self.compile();
+    }
+
+    pub fn clear(&mut self) {
+        self.query.clear();
+        self.editing = false;
+        self.compile();
+    }
+
+    // 검색어나 모드가 바뀔 때마다 정규식을 다시 만듦 (행마다 컴파일하지 않도록)
+    fn compile(&mut self) {
+        self.error = None;
+        self.pattern = match self.mode {
+            _ if self.query.is_empty() => None,
+            SearchMode::Substring => Regex::new(&format!("(?i){}", regex::escape(&self.query))).ok(),
+            SearchMode::Regex => match Regex::new(&self.query) {
+                Ok(pattern) => Some(pattern),
+                Err(e) => {
+                    // 에러 메시지의 마지막 줄만 ("error: unclosed group")
+                    let message = e.to_string();
+                    let last = message.lines().last().unwrap_or_default().trim();
+                    self.error = Some(last.trim_start_matches("error: ").to_string());
+                    None
+                }
+            },
+            SearchMode::Fuzzy => None,
+        };
+    }
+
+    pub fn is_active(&self) -> bool {
+        !self.query.is_empty() && self.error.is_none()
+    }
+
+    // 일치하면 강조할 byte 범위 (빈 문자열에 일치하는 정규식은 범위 없이 일치)
+    pub fn find(&self, text: &str) -> Option<Vec<Range<usize>>> {
+        if !self.is_active() {
+            return None;
+        }
+        match (&self.pattern, self.mode) {
+            (_, SearchMode::Fuzzy) => fuzzy_match(text, &self.query),
+            (Some(pattern), _) if pattern.is_match(text) => Some(
+                pattern
+                    .find_iter(text)
+                    .map(|found| found.range())
+                    .filter(|range| !range.is_empty())
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    // 텍스트 셀 중 하나라도 일치하는 행 (숫자 컬럼은 검색하지 않음)
+    pub fn matches_row(&self, row: &[CellValue]) -> bool {
+        !self.is_active()
+            || row
+                .iter()
+                .any(|cell| matches!(cell, CellValue::Text(text) if self.find(text).is_some()))
+    }
+
+    // 제목과 내보내기에 표시 ("/api/ (regex)")
+    pub fn describe(&self) -> Option<String> {
+        self.is_active().then(|| format!("/{}/ ({})", self.query, self.mode.name()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text_search(mode: SearchMode, query: &str) -> TextSearch {
+        let mut search = TextSearch { mode, ..Default::default() };
+        for c in query.chars() {
+            search.push(c);
+        }
+        search
+    }
+
+    // 강조되는 부분 문자열
+    fn highlighted<'a>(search: &TextSearch, text: &'a str) -> Option<Vec<&'a str>> {
+        search.find(text).map(|ranges| ranges.into_iter().map(|range| &text[range]).collect())
+    }
+
+    #[test]
+    fn test_substring_ignores_case_and_escapes() {
+        let search = text_search(SearchMode::Substring, "API.");
+        assert_eq!(highlighted(&search, "my-api.svc"), Some(vec!["api."]));
+        // 정규식 문법으로 해석하지 않음
+        assert_eq!(search.find("api-1"), None);
+        assert_eq!(search.describe().as_deref(), Some("/API./ (substring)"));
+    }
+
+    #[test]
+    fn test_regex_ranges() {
+        let search = text_search(SearchMode::Regex, "^api-[0-9]+");
+        assert_eq!(highlighted(&search, "api-12-x"), Some(vec!["api-12"]));
+        assert_eq!(search.find("web-api-1"), None);
+
+        // 빈 문자열에 일치: 강조 범위 없이 일치
+        let search = text_search(SearchMode::Regex, "x*");
+        assert_eq!(highlighted(&search, "abc"), Some(vec![]));
+    }
+
+    #[test]
+    fn test_invalid_regex_does_not_filter() {
+        let mut search = text_search(SearchMode::Regex, "api-(");
+        assert_eq!(search.error.as_deref(), Some("unclosed group"));
+        assert!(!search.is_active());
+        assert_eq!(search.find("api-("), None);
+        assert!(search.matches_row(&[CellValue::Text("web".to_string())]));
+        assert_eq!(search.describe(), None);
+
+        // 고치면 다시 필터
+        search.pop();
+        assert_eq!(search.error, None);
+        assert!(!search.matches_row(&[CellValue::Text("web".to_string())]));
+
+        // substring 모드에서는 같은 검색어도 문자 그대로 검색
+        search.push('(');
+        search.cycle_mode();
+        search.cycle_mode();
+        assert_eq!(search.mode, SearchMode::Substring);
+        assert_eq!(search.error, None);
+        assert_eq!(highlighted(&search, "old-API-(1)"), Some(vec!["API-("]));
+    }
+
+    #[test]
+    fn test_matches_row_checks_text_cells_only() {
+        let search = text_search(SearchMode::Substring, "12");
+        assert!(!search.matches_row(&[CellValue::Text("api".to_string()), CellValue::Count(12)]));
+        assert!(search.matches_row(&[CellValue::Text("api-12".to_string()), CellValue::Count(0)]));
+    }
+}
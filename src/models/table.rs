@@ -0,0 +1,263 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::models::config::{SearchConfig, SortConfig};
+use crate::models::resource::{Cpu, Memory};
+use crate::util::common::{format_cpu, format_memory};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+// 타입을 유지한 셀 값 (정렬, 합계, 내보내기에서 원래 의미를 사용)
+#[derive(Debug, Clone, PartialEq)]
+pub enum CellValue {
+    Text(String),
+    Cpu(Cpu),
+    Memory(Memory),
+    Count(i64),          // 합계를 낼 수 있는 개수
+    Number(Option<i64>), // 합계를 내지 않는 숫자 (priority, replica 수 등)
+}
+
+impl CellValue {
+    // 문자열은 오름차순, 숫자는 큰 값이 먼저 오도록 내림차순
+    pub fn compare(&self, other: &CellValue) -> Ordering {
+        match (self, other) {
+            (CellValue::Text(a), CellValue::Text(b)) => a.cmp(b),
+            (CellValue::Cpu(a), CellValue::Cpu(b)) => b.cmp(a),
+            (CellValue::Memory(a), CellValue::Memory(b)) => b.cmp(a),
+            (CellValue::Count(a), CellValue::Count(b)) => b.cmp(a),
+            (CellValue::Number(a), CellValue::Number(b)) => b.cmp(a),
+            _ => Ordering::Equal,
+        }
+    }
+
+    // 같은 종류의 값끼리 더함 (Text/Number는 합계 없음)
+    fn sum<'a>(values: impl Iterator<Item = &'a CellValue>, empty: CellValue) -> CellValue {
+        values.fold(empty, |acc, value| match (acc, value) {
+            (CellValue::Cpu(a), CellValue::Cpu(b)) => CellValue::Cpu(a + *b),
+            (CellValue::Memory(a), CellValue::Memory(b)) => CellValue::Memory(a + *b),
+            (CellValue::Count(a), CellValue::Count(b)) => CellValue::Count(a.saturating_add(*b)),
+            (acc, _) => acc,
+        })
+    }
+}
+
+impl fmt::Display for CellValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CellValue::Text(value) => write!(f, "{}", value),
+            CellValue::Cpu(value) => write!(f, "{}", format_cpu(*value)),
+            CellValue::Memory(value) => write!(f, "{}", format_memory(*value)),
+            CellValue::Count(value) => write!(f, "{}", value),
+            CellValue::Number(Some(value)) => write!(f, "{}", value),
+            CellValue::Number(None) => Ok(()),
+        }
+    }
+}
+
+// 컬럼 정의: 헤더, 너비, 정렬 방향, 값 추출, 포맷, 비교
+pub trait Column<R> {
+    fn header(&self) -> &'static str;
+    fn width(&self) -> u16;
+    fn align(&self) -> Align;
+    fn value(&self, row: &R) -> CellValue;
+    fn summable(&self) -> bool;
+
+    fn format(&self, row: &R) -> String {
+        self.value(row).to_string()
+    }
+
+    fn compare(&self, a: &R, b: &R) -> Ordering {
+        self.value(a).compare(&self.value(b))
+    }
+}
+
+// 뷰별 컬럼 목록(registry)에 사용하는 기본 구현
+pub struct ColumnDef<R> {
+    pub header: &'static str,
+    pub width: u16,
+    pub align: Align,
+    pub value: fn(&R) -> CellValue,
+    pub summable: bool, // TOTAL 행에 합계 표시 여부
+}
+
+impl<R> ColumnDef<R> {
+    pub const fn text(header: &'static str, width: u16, value: fn(&R) -> CellValue) -> Self {
+        Self { header, width, align: Align::Left, value, summable: false }
+    }
+
+    pub const fn number(header: &'static str, width: u16, value: fn(&R) -> CellValue) -> Self {
+        Self { header, width, align: Align::Right, value, summable: false }
+    }
+
+    pub const fn total(header: &'static str, width: u16, value: fn(&R) -> CellValue) -> Self {
+        Self { header, width, align: Align::Right, value, summable: true }
+    }
+}
+
+impl<R> Column<R> for ColumnDef<R> {
+    fn header(&self) -> &'static str {
+        self.header
+    }
+
+    fn width(&self) -> u16 {
+        self.width
+    }
+
+    fn align(&self) -> Align {
+        self.align
+    }
+
+    fn value(&self, row: &R) -> CellValue {
+        (self.value)(row)
+    }
+
+    fn summable(&self) -> bool {
+        self.summable
+    }
+}
+
+// 화면/내보내기에 공통으로 사용하는 테이블 (헤더와 TOTAL 행은 rows와 분리)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Table {
+    pub headers: Vec<&'static str>,
+    pub widths: Vec<u16>,
+    pub aligns: Vec<Align>,
+    pub summable: Vec<bool>,
+    pub rows: Vec<Vec<CellValue>>,
+    pub total: Vec<CellValue>,
+}
+
+impl Table {
+    // 컬럼 정의에 따라 필터, 정렬, 합계를 적용하여 테이블 생성
+    pub fn build<R, C: Column<R>>(
+        rows: &[R],
+        columns: &[C],
+        search_config: Option<SearchConfig>,
+        sort_config: Option<SortConfig>,
+    ) -> Self {
+        let mut items: Vec<&R> = rows.iter().collect();
+
+        // 필터 (해당 컬럼의 표시값이 검색어와 같은 행만)
+        if let Some(search_config) = search_config {
+            if let Some(column) = columns.get(search_config.column) {
+                let word = search_config.get_word();
+                items.retain(|row| column.format(row) == word);
+            }
+        }
+
+        // 정렬 (stable sort이므로 같은 값은 기존 순서 유지)
+        if let Some(sort_config) = sort_config {
+            if let Some(column) = columns.get(sort_config.column) {
+                items.sort_by(|a, b| column.compare(a, b));
+            }
+        }
+
+        let cells: Vec<Vec<CellValue>> = items
+            .iter()
+            .map(|row| columns.iter().map(|column| column.value(row)).collect())
+            .collect();
+
+        let summable: Vec<bool> = columns.iter().map(|column| column.summable()).collect();
+        let total = total_row(&summable, &cells);
+
+        Self {
+            headers: columns.iter().map(|column| column.header()).collect(),
+            widths: columns.iter().map(|column| column.width()).collect(),
+            aligns: columns.iter().map(|column| column.align()).collect(),
+            summable,
+            rows: cells,
+            total,
+        }
+    }
+
+    // 표시값 기준으로 행을 걸러내고 TOTAL 행을 다시 계산 (UI의 '/' 검색)
+    pub fn retain_rows(&mut self, mut keep: impl FnMut(&[CellValue]) -> bool) {
+        let count = self.rows.len();
+        self.rows.retain(|row| keep(row));
+        if self.rows.len() != count {
+            self.total = total_row(&self.summable, &self.rows);
+        }
+    }
+}
+
+// 첫 컬럼은 "TOTAL", 합계 컬럼은 같은 종류의 값끼리 더함
+fn total_row(summable: &[bool], cells: &[Vec<CellValue>]) -> Vec<CellValue> {
+    summable
+        .iter()
+        .enumerate()
+        .map(|(i, &summable)| {
+            if i == 0 {
+                CellValue::Text("TOTAL".to_string())
+            } else if summable {
+                let empty = match cells.first().map(|row| &row[i]) {
+                    Some(CellValue::Cpu(_)) => CellValue::Cpu(Cpu::default()),
+                    Some(CellValue::Memory(_)) => CellValue::Memory(Memory::default()),
+                    Some(CellValue::Count(_)) => CellValue::Count(0),
+                    _ => CellValue::Text(String::new()),
+                };
+                CellValue::sum(cells.iter().map(|row| &row[i]), empty)
+            } else {
+                CellValue::Text(String::new())
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COLUMNS: &[ColumnDef<(&str, &str, i64)>] = &[
+        ColumnDef::text("Pod Name", 20, |row| CellValue::Text(row.0.to_string())),
+        ColumnDef::text("Node", 20, |row| CellValue::Text(row.1.to_string())),
+        ColumnDef::total("CPU Req.", 15, |row| CellValue::Cpu(Cpu::from_nanocores(row.2 * 1_000_000))),
+    ];
+
+    const ROWS: &[(&str, &str, i64)] = &[("a", "worker-1", 100), ("b", "worker-2", 300), ("c", "worker-1", 200)];
+
+    fn names(table: &Table) -> Vec<String> {
+        table.rows.iter().map(|row| row[0].to_string()).collect()
+    }
+
+    fn cpu_total(table: &Table) -> CellValue {
+        table.total[2].clone()
+    }
+
+    #[test]
+    fn test_build_sorts_filters_and_totals() {
+        // 정렬 없음: 입력 순서, TOTAL은 전체 합계
+        let table = Table::build(ROWS, COLUMNS, None, None);
+        assert_eq!(table.headers, vec!["Pod Name", "Node", "CPU Req."]);
+        assert_eq!(names(&table), vec!["a", "b", "c"]);
+        assert_eq!(table.total[0], CellValue::Text("TOTAL".to_string()));
+        assert_eq!(table.total[1], CellValue::Text(String::new()));
+        assert_eq!(cpu_total(&table), CellValue::Cpu(Cpu::from_nanocores(600_000_000)));
+
+        // CPU는 큰 값이 먼저
+        let table = Table::build(ROWS, COLUMNS, None, Some(SortConfig::new(2)));
+        assert_eq!(names(&table), vec!["b", "c", "a"]);
+
+        // 필터된 행만 합산
+        let table = Table::build(ROWS, COLUMNS, Some(SearchConfig::new(1, "worker-1")), Some(SortConfig::new(0)));
+        assert_eq!(names(&table), vec!["a", "c"]);
+        assert_eq!(cpu_total(&table), CellValue::Cpu(Cpu::from_nanocores(300_000_000)));
+    }
+
+    #[test]
+    fn test_retain_rows_recomputes_total() {
+        let mut table = Table::build(ROWS, COLUMNS, None, None);
+        table.retain_rows(|row| row[1] == CellValue::Text("worker-2".to_string()));
+        assert_eq!(names(&table), vec!["b"]);
+        assert_eq!(cpu_total(&table), CellValue::Cpu(Cpu::from_nanocores(300_000_000)));
+
+        // 일치하는 행이 없으면 값의 종류를 알 수 없으므로 빈 칸
+        table.retain_rows(|_| false);
+        assert!(table.rows.is_empty());
+        assert_eq!(table.total[0], CellValue::Text("TOTAL".to_string()));
+        assert_eq!(table.total[2], CellValue::Text(String::new()));
+    }
+}
@@ -0,0 +1,65 @@
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use serde::Serialize;
+
+use crate::models::table::{CellValue, ColumnDef};
+
+// 파싱할 수 없는 quantity 값 (합계에는 0으로 집계됨)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ParseWarning {
+    pub kind: &'static str,
+    pub namespace: String,
+    pub name: String,
+    pub container: String, // 컨테이너와 무관한 값(Node allocatable, PVC 등)은 빈 문자열
+    pub field: String,     // 예: requests.cpu
+    pub value: String,     // 원본 값
+    pub message: String,
+}
+
+// 경고를 남길 대상 객체
+#[derive(Debug, Clone, Default)]
+pub struct WarningSource {
+    pub kind: &'static str,
+    pub namespace: String,
+    pub name: String,
+    pub container: String,
+}
+
+impl WarningSource {
+    pub fn new(kind: &'static str, metadata: &ObjectMeta) -> Self {
+        Self {
+            kind,
+            namespace: metadata.namespace.clone().unwrap_or_default(),
+            name: metadata.name.clone().unwrap_or_default(),
+            container: String::new(),
+        }
+    }
+
+    pub fn with_container(&self, container: &str) -> Self {
+        Self {
+            container: container.to_string(),
+            ..self.clone()
+        }
+    }
+
+    pub fn warning(&self, field: String, value: &str, message: String) -> ParseWarning {
+        ParseWarning {
+            kind: self.kind,
+            namespace: self.namespace.clone(),
+            name: self.name.clone(),
+            container: self.container.clone(),
+            field,
+            value: value.to_string(),
+            message,
+        }
+    }
+}
+
+pub const WARNING_COLUMNS: &[ColumnDef<ParseWarning>] = &[
+    ColumnDef::text("Kind", 12, |warning| CellValue::Text(warning.kind.to_string())),
+    ColumnDef::text("Namespace", 20, |warning| CellValue::Text(warning.namespace.clone())),
+    ColumnDef::text("Name", 35, |warning| CellValue::Text(warning.name.clone())),
+    ColumnDef::text("Container", 20, |warning| CellValue::Text(warning.container.clone())),
+    ColumnDef::text("Field", 18, |warning| CellValue::Text(warning.field.clone())),
+    ColumnDef::text("Value", 16, |warning| CellValue::Text(warning.value.clone())),
+    ColumnDef::text("Error", 60, |warning| CellValue::Text(warning.message.clone())),
+];
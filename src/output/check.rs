@@ -0,0 +1,417 @@
+use std::fs;
+use std::path::Path;
+
+use crate::api::context::KubeContext;
+use crate::api::dataset::{fetch_cluster_usage, ClusterUsage};
+use crate::api::snapshot;
+use crate::models::cli::CheckArgs;
+use crate::models::error::AppError;
+use crate::models::policy::Policy;
+use crate::models::resource::{Cpu, Memory, Resources};
+use crate::models::table::{CellValue, ColumnDef, Table};
+use crate::output::{print_warnings, text};
+use crate::util::common::{format_count, format_cpu, format_memory, parse_cpu, parse_memory, percent};
+
+// 위반이 있으면 이 코드로 종료 (1은 정책 파일/조회 에러)
+const VIOLATION_EXIT_CODE: i32 = 2;
+
+// 목록에 표시하는 Pod 위반의 최대 개수 (나머지는 개수만 표시)
+const MAX_POD_VIOLATIONS: usize = 100;
+
+struct Violation {
+    rule: &'static str,
+    object: String,
+    value: String,
+    threshold: String,
+}
+
+const VIOLATION_COLUMNS: &[ColumnDef<Violation>] = &[
+    ColumnDef::text("Rule", 25, |row| CellValue::Text(row.rule.to_string())),
+    ColumnDef::text("Object", 40, |row| CellValue::Text(row.object.clone())),
+    ColumnDef::text("Value", 25, |row| CellValue::Text(row.value.clone())),
+    ColumnDef::text("Threshold", 15, |row| CellValue::Text(row.threshold.clone())),
+];
+
+// 정책 파일의 quantity를 미리 변환 (조회 전에 오류 확인)
+struct Thresholds {
+    min_free_cpu: Option<Cpu>,
+    min_free_memory: Option<Memory>,
+}
+
+fn load_policy(path: &Path) -> Result<(Policy, Thresholds), AppError> {
+    let content = fs::read_to_string(path).map_err(|e| AppError::IoError(format!("Failed to read {}: {}", path.display(), e)))?;
+    let policy: Policy =
+        toml::from_str(&content).map_err(|e| AppError::ParseError(format!("{}: {}", path.display(), e)))?;
+    if policy.is_empty() {
+        return Err(AppError::ParseError(format!("{}: no rules configured", path.display())));
+    }
+
+    let invalid = |key: &str, e: AppError| {
+        let message = match e {
+            AppError::ParseError(message) => message,
+            e => e.to_string(),
+        };
+        AppError::ParseError(format!("{}: cluster.{}: {}", path.display(), key, message))
+    };
+    let thresholds = Thresholds {
+        min_free_cpu: policy
+            .cluster
+            .min_free_cpu
+            .as_deref()
+            .map(parse_cpu)
+            .transpose()
+            .map_err(|e| invalid("min_free_cpu", e))?,
+        min_free_memory: policy
+            .cluster
+            .min_free_memory
+            .as_deref()
+            .map(parse_memory)
+            .transpose()
+            .map_err(|e| invalid("min_free_memory", e))?,
+    };
+    Ok((policy, thresholds))
+}
+
+fn format_percent(value: f64) -> String {
+    format!("{:.1}%", value)
+}
+
+// 여유 용량은 0이나 음수일 수 있음 (format_cpu/format_memory는 0을 빈 문자열로 표시)
+fn amount(formatted: String) -> String {
+    if formatted.is_empty() { "0".to_string() } else { formatted }
+}
+
+fn check_cluster(thresholds: &Thresholds, usage: &ClusterUsage, violations: &mut Vec<Violation>) {
+    let nodes = &usage.nodes.rows;
+    let allocatable_cpu: Cpu = nodes.iter().map(|row| row.resources.allocatable_cpu).sum();
+    let allocatable_memory: Memory = nodes.iter().map(|row| row.resources.allocatable_memory).sum();
+    // namespace 합계에는 아직 스케줄되지 않은 Pod도 포함 (자리가 필요하므로)
+    let mut requested = Resources::new();
+    for namespace in &usage.namespaces {
+        requested.add(&namespace.resources);
+    }
+
+    let free_cpu = allocatable_cpu - requested.cpu_request;
+    if let Some(min) = thresholds.min_free_cpu {
+        if free_cpu < min {
+            violations.push(Violation {
+                rule: "cluster.min_free_cpu",
+                object: "cluster".to_string(),
+                value: format!("{} free of {}", amount(format_cpu(free_cpu)), amount(format_cpu(allocatable_cpu))),
+                threshold: format!("min {}", amount(format_cpu(min))),
+            });
+        }
+    }
+    let free_memory = allocatable_memory - requested.memory_request;
+    if let Some(min) = thresholds.min_free_memory {
+        if free_memory < min {
+            violations.push(Violation {
+                rule: "cluster.min_free_memory",
+                object: "cluster".to_string(),
+                value: format!(
+                    "{} free of {}",
+                    amount(format_memory(free_memory)),
+                    amount(format_memory(allocatable_memory))
+                ),
+                threshold: format!("min {}", amount(format_memory(min))),
+            });
+        }
+    }
+}
+
+fn check_nodes(policy: &Policy, usage: &ClusterUsage, violations: &mut Vec<Violation>) {
+    let mut found: Vec<(f64, Violation)> = Vec::new();
+    for node in &usage.nodes.rows {
+        let resources = &node.resources;
+        let rules = [
+            (
+                "nodes.max_cpu_request_percent",
+                policy.nodes.max_cpu(),
+                percent(resources.base.cpu_request.as_nanocores(), resources.allocatable_cpu.as_nanocores()),
+                format!("{} of {}", format_cpu(resources.base.cpu_request), format_cpu(resources.allocatable_cpu)),
+            ),
+            (
+                "nodes.max_memory_request_percent",
+                policy.nodes.max_memory(),
+                percent(resources.base.memory_request.as_bytes(), resources.allocatable_memory.as_bytes()),
+                format!(
+                    "{} of {}",
+                    format_memory(resources.base.memory_request),
+                    format_memory(resources.allocatable_memory)
+                ),
+            ),
+        ];
+        for (rule, max, used, amounts) in rules {
+            if let (Some(max), Some(used)) = (max, used) {
+                if used > max {
+                    found.push((
+                        used,
+                        Violation {
+                            rule,
+                            object: format!("node {}", node.name),
+                            value: format!("{} ({})", format_percent(used), amounts),
+                            threshold: format!("max {}", format_percent(max)),
+                        },
+                    ));
+                }
+            }
+        }
+    }
+    // 비율이 높은 순서로
+    found.sort_by(|a, b| b.0.total_cmp(&a.0));
+    violations.extend(found.into_iter().map(|(_, violation)| violation));
+}
+
+fn check_namespaces(policy: &Policy, usage: &ClusterUsage, violations: &mut Vec<Violation>) {
+    let nodes = &usage.nodes.rows;
+    let allocatable_cpu: Cpu = nodes.iter().map(|row| row.resources.allocatable_cpu).sum();
+    let allocatable_memory: Memory = nodes.iter().map(|row| row.resources.allocatable_memory).sum();
+
+    let mut found: Vec<(f64, Violation)> = Vec::new();
+    for namespace in &usage.namespaces {
+        let resources = &namespace.resources;
+        let rules = [
+            (
+                "namespaces.max_cpu_share_percent",
+                policy.namespaces.max_cpu(),
+                percent(resources.cpu_request.as_nanocores(), allocatable_cpu.as_nanocores()),
+                format!("{} of {}", format_cpu(resources.cpu_request), format_cpu(allocatable_cpu)),
+            ),
+            (
+                "namespaces.max_memory_share_percent",
+                policy.namespaces.max_memory(),
+                percent(resources.memory_request.as_bytes(), allocatable_memory.as_bytes()),
+                format!("{} of {}", format_memory(resources.memory_request), format_memory(allocatable_memory)),
+            ),
+        ];
+        for (rule, max, share, amounts) in rules {
+            if let (Some(max), Some(share)) = (max, share) {
+                if share > max {
+                    found.push((
+                        share,
+                        Violation {
+                            rule,
+                            object: format!("namespace {}", namespace.name),
+                            value: format!("{} ({})", format_percent(share), amounts),
+                            threshold: format!("max {}", format_percent(max)),
+                        },
+                    ));
+                }
+            }
+        }
+    }
+    found.sort_by(|a, b| b.0.total_cmp(&a.0));
+    violations.extend(found.into_iter().map(|(_, violation)| violation));
+}
+
+fn check_pods(policy: &Policy, usage: &ClusterUsage, violations: &mut Vec<Violation>) {
+    let pods_policy = &policy.pods;
+    let mut pods: Vec<_> = usage
+        .pods
+        .iter()
+        .filter(|pod| pod.counts_toward_requests() && !pods_policy.exclude_namespaces.contains(&pod.namespace))
+        .collect();
+    pods.sort_by(|a, b| a.namespace.cmp(&b.namespace).then(a.name.cmp(&b.name)));
+
+    for pod in pods {
+        let resources = &pod.resources;
+        let rules = [
+            (
+                "pods.require_requests",
+                pods_policy.require_requests,
+                resources.cpu_request == Cpu::default(),
+                resources.memory_request == Memory::default(),
+            ),
+            (
+                "pods.require_limits",
+                pods_policy.require_limits,
+                resources.cpu_limit == Cpu::default(),
+                resources.memory_limit == Memory::default(),
+            ),
+        ];
+        for (rule, enabled, cpu_missing, memory_missing) in rules {
+            let missing: Vec<&str> = [(cpu_missing, "CPU"), (memory_missing, "memory")]
+                .into_iter()
+                .filter_map(|(is_missing, resource)| is_missing.then_some(resource))
+                .collect();
+            if enabled && !missing.is_empty() {
+                violations.push(Violation {
+                    rule,
+                    object: format!("pod {}/{}", pod.namespace, pod.name),
+                    value: format!("missing {}", missing.join(", ")),
+                    threshold: String::new(),
+                });
+            }
+        }
+    }
+}
+
+// nightly job, cron용 정책 검사: 위반을 출력하고 종료 코드로 결과 전달
+pub async fn run(ctx: &KubeContext, args: &CheckArgs, record: Option<&Path>) -> Result<(), AppError> {
+    let (policy, thresholds) = load_policy(&args.policy)?;
+    let (usage, warnings) = fetch_cluster_usage(ctx).await?;
+    if let Some(path) = record {
+        snapshot::record_usage(path, &usage)?;
+    }
+    print_warnings(&warnings);
+
+    let mut violations = Vec::new();
+    // allocatable이 필요한 규칙은 노드 조회 권한이 없으면 건너뜀
+    if usage.nodes.allocatable_hidden {
+        eprintln!("Skipped node, namespace and cluster rules: no permission to list nodes");
+    } else {
+        check_cluster(&thresholds, &usage, &mut violations);
+        check_nodes(&policy, &usage, &mut violations);
+        check_namespaces(&policy, &usage, &mut violations);
+    }
+
+    let mut pod_violations = Vec::new();
+    check_pods(&policy, &usage, &mut pod_violations);
+    let pod_count = pod_violations.len();
+    pod_violations.truncate(MAX_POD_VIOLATIONS);
+    violations.extend(pod_violations);
+
+    let total = violations.len() + pod_count.saturating_sub(MAX_POD_VIOLATIONS);
+    if total == 0 {
+        println!(
+            "OK: no violations ({} nodes, {} namespaces, {} pods checked)",
+            format_count(usage.nodes.rows.len()),
+            format_count(usage.namespaces.len()),
+            format_count(usage.pods.len())
+        );
+        return Ok(());
+    }
+
+    println!("{}", text::render(&Table::build(&violations, VIOLATION_COLUMNS, None, None), false));
+    if pod_count > MAX_POD_VIOLATIONS {
+        println!("... and {} more pod violations", format_count(pod_count - MAX_POD_VIOLATIONS));
+    }
+    println!("\n{} violations of {}", format_count(total), args.policy.display());
+    std::process::exit(VIOLATION_EXIT_CODE);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::namespace::NamespaceRow;
+    use crate::api::node::{NodeData, NodeRow};
+    use crate::api::pod::{PodRow, RequestTotals};
+    use crate::models::resource::NodeResources;
+
+    fn pod(namespace: &str, name: &str, node: &str, status: &str, requests: (&str, &str), limits: bool) -> PodRow {
+        let cpu = parse_cpu(requests.0).unwrap();
+        let memory = parse_memory(requests.1).unwrap();
+        let resources = Resources {
+            cpu_request: cpu,
+            memory_request: memory,
+            cpu_limit: if limits { cpu } else { Cpu::default() },
+            memory_limit: if limits { memory } else { Memory::default() },
+        };
+        PodRow {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+            status: status.to_string(),
+            node: node.to_string(),
+            resources,
+            priority_class: String::new(),
+            priority: None,
+            workload: format!("Pod/{}", name),
+        }
+    }
+
+    // fetch_cluster_usage와 같이 RequestTotals로 노드/namespace 합계 계산
+    // 노드 2개 (각 4 CPU, 8Gi), 완료된 job은 모든 규칙에서 제외되어야 함
+    fn usage() -> ClusterUsage {
+        let pods = vec![
+            pod("a", "web", "n1", "Running", ("3", "2Gi"), true),
+            pod("a", "job", "n1", "Succeeded", ("4", "4Gi"), false),
+            pod("b", "api", "n2", "Running", ("1", "1Gi"), false),
+            pod("b", "pending", "", "Pending", ("500m", "512Mi"), true),
+            pod("kube-system", "dns", "n2", "Running", ("0", "0"), false),
+        ];
+        let mut totals = RequestTotals::default();
+        for pod in &pods {
+            totals.add(pod);
+        }
+
+        let rows = ["n1", "n2"]
+            .into_iter()
+            .map(|name| {
+                let mut resources = NodeResources::new();
+                resources.allocatable_cpu = parse_cpu("4").unwrap();
+                resources.allocatable_memory = parse_memory("8Gi").unwrap();
+                if let Some(requests) = totals.by_node.get(name) {
+                    resources.base.add(requests);
+                }
+                NodeRow { name: name.to_string(), resources }
+            })
+            .collect();
+        let mut namespaces: Vec<NamespaceRow> = totals
+            .by_namespace
+            .into_iter()
+            .map(|(name, resources)| NamespaceRow { name, resources })
+            .collect();
+        namespaces.sort_by(|a, b| a.name.cmp(&b.name));
+
+        ClusterUsage { nodes: NodeData { rows, allocatable_hidden: false }, namespaces, pods }
+    }
+
+    fn evaluate(policy: &str) -> Vec<(&'static str, String)> {
+        let policy: Policy = toml::from_str(policy).unwrap();
+        let thresholds = Thresholds {
+            min_free_cpu: policy.cluster.min_free_cpu.as_deref().map(|quantity| parse_cpu(quantity).unwrap()),
+            min_free_memory: policy.cluster.min_free_memory.as_deref().map(|quantity| parse_memory(quantity).unwrap()),
+        };
+        let usage = usage();
+        let mut violations = Vec::new();
+        check_cluster(&thresholds, &usage, &mut violations);
+        check_nodes(&policy, &usage, &mut violations);
+        check_namespaces(&policy, &usage, &mut violations);
+        check_pods(&policy, &usage, &mut violations);
+        violations.into_iter().map(|violation| (violation.rule, violation.object)).collect()
+    }
+
+    #[test]
+    fn test_check_rules() {
+        let cases: &[(&str, &[(&str, &str)])] = &[
+            // n1: CPU 75%, 메모리 25% / n2: CPU 25%, 메모리 12.5%
+            ("[nodes]\nmax_cpu_request_percent = 70", &[("nodes.max_cpu_request_percent", "node n1")]),
+            ("[nodes]\nmax_cpu_request_percent = 80", &[]),
+            (
+                "[nodes]\nmax_request_percent = 20",
+                &[
+                    ("nodes.max_cpu_request_percent", "node n1"),
+                    ("nodes.max_memory_request_percent", "node n1"),
+                    ("nodes.max_cpu_request_percent", "node n2"),
+                ],
+            ),
+            // a: CPU 37.5%, 메모리 12.5% / b: CPU 18.75%, 메모리 9.4% (Pending Pod 포함)
+            ("[namespaces]\nmax_cpu_share_percent = 30", &[("namespaces.max_cpu_share_percent", "namespace a")]),
+            (
+                "[namespaces]\nmax_share_percent = 10",
+                &[
+                    ("namespaces.max_cpu_share_percent", "namespace a"),
+                    ("namespaces.max_cpu_share_percent", "namespace b"),
+                    ("namespaces.max_memory_share_percent", "namespace a"),
+                ],
+            ),
+            ("[pods]\nrequire_requests = true", &[("pods.require_requests", "pod kube-system/dns")]),
+            ("[pods]\nrequire_requests = true\nexclude_namespaces = [\"kube-system\"]", &[]),
+            (
+                "[pods]\nrequire_limits = true",
+                &[("pods.require_limits", "pod b/api"), ("pods.require_limits", "pod kube-system/dns")],
+            ),
+            // 여유 용량: CPU 3.5, 메모리 12.5Gi
+            ("[cluster]\nmin_free_cpu = \"3\"", &[]),
+            ("[cluster]\nmin_free_cpu = \"4\"", &[("cluster.min_free_cpu", "cluster")]),
+            ("[cluster]\nmin_free_memory = \"12Gi\"", &[]),
+            ("[cluster]\nmin_free_memory = \"13Gi\"", &[("cluster.min_free_memory", "cluster")]),
+        ];
+
+        for (policy, expected) in cases {
+            let expected: Vec<(&str, String)> =
+                expected.iter().map(|(rule, object)| (*rule, object.to_string())).collect();
+            assert_eq!(evaluate(policy), expected, "policy: {}", policy);
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use crate::models::cli::Units;
+use crate::models::error::AppError;
+use crate::models::table::{CellValue, Table};
+use crate::util::common::{format_cores, format_millicores};
+
+const BYTES_PER_GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+// 스프레드시트에서 숫자로 인식되도록 단위 없이 출력
+fn field(value: &CellValue, units: Units) -> String {
+    match (value, units) {
+        (CellValue::Cpu(cpu), Units::Raw) => format_millicores(*cpu),
+        (CellValue::Cpu(cpu), Units::CoresGib) => format_cores(*cpu),
+        (CellValue::Memory(memory), Units::Raw) => memory.as_bytes().to_string(),
+        (CellValue::Memory(memory), Units::CoresGib) => (memory.as_bytes() as f64 / BYTES_PER_GIB).to_string(),
+        (value, _) => value.to_string(),
+    }
+}
+
+// 단위는 헤더에 표시 ("CPU Req. (m)", "Mem Req. (GiB)")
+fn header(header: &str, sample: Option<&CellValue>, units: Units) -> String {
+    let unit = match (sample, units) {
+        (Some(CellValue::Cpu(_)), Units::Raw) => "m",
+        (Some(CellValue::Cpu(_)), Units::CoresGib) => "cores",
+        (Some(CellValue::Memory(_)), Units::Raw) => "bytes",
+        (Some(CellValue::Memory(_)), Units::CoresGib) => "GiB",
+        _ => return header.to_string(),
+    };
+    format!("{} ({})", header, unit)
+}
+
+// CSV(,) 또는 TSV(\t)로 출력, 필요한 경우에만 따옴표 처리
+pub fn render(table: &Table, delimiter: u8, units: Units, include_total: bool) -> Result<String, AppError> {
+    let mut writer = csv::WriterBuilder::new().delimiter(delimiter).from_writer(Vec::new());
+    let write_error = |e: csv::Error| AppError::IoError(format!("Failed to write delimited output: {}", e));
+
+    // 컬럼 타입은 첫 행 기준 (행이 없으면 TOTAL 행)
+    let samples = table.rows.first().unwrap_or(&table.total);
+    writer
+        .write_record(
+            table
+                .headers
+                .iter()
+                .enumerate()
+                .map(|(i, name)| header(name, samples.get(i), units)),
+        )
+        .map_err(write_error)?;
+
+    let total = include_total.then_some(&table.total);
+    for row in table.rows.iter().chain(total) {
+        writer
+            .write_record(row.iter().map(|value| field(value, units)))
+            .map_err(write_error)?;
+    }
+
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| AppError::IoError(format!("Failed to write delimited output: {}", e)))?;
+    String::from_utf8(bytes).map_err(|e| AppError::ParseError(format!("Invalid UTF-8 in output: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::resource::{Cpu, Memory};
+    use crate::models::table::ColumnDef;
+
+    const COLUMNS: &[ColumnDef<(&str, Cpu, Memory)>] = &[
+        ColumnDef::text("Pod Name", 20, |row| CellValue::Text(row.0.to_string())),
+        ColumnDef::total("CPU Req.", 15, |row| CellValue::Cpu(row.1)),
+        ColumnDef::total("Mem Req.", 15, |row| CellValue::Memory(row.2)),
+    ];
+
+    #[test]
+    fn test_raw_rows_add_up_to_total() {
+        // 100u 두 개: millicore로 올림하면 1 + 1 != 1
+        let rows = [
+            ("a", Cpu::from_nanocores(100_000), Memory::from_bytes(1024)),
+            ("b", Cpu::from_nanocores(100_000), Memory::from_bytes(2048)),
+        ];
+        let table = Table::build(&rows, COLUMNS, None, None);
+        let output = render(&table, b',', Units::Raw, true).unwrap();
+        assert_eq!(output, "Pod Name,CPU Req. (m),Mem Req. (bytes)\na,0.1,1024\nb,0.1,2048\nTOTAL,0.2,3072\n");
+    }
+}
@@ -0,0 +1,368 @@
+use std::collections::{BTreeMap, BTreeSet};
+use kube::config::Kubeconfig;
+use std::path::Path;
+
+use crate::api::context::KubeContext;
+use crate::api::dataset::fetch_cluster_usage;
+use crate::api::snapshot::{self, Snapshot};
+use crate::models::cli::{DiffArgs, DiffSort};
+use crate::models::config::{RequestConfig, ScopeConfig};
+use crate::models::error::AppError;
+use crate::models::resource::{Cpu, Memory, Resources};
+use crate::models::table::{CellValue, ColumnDef, Table};
+use crate::output::{print_warnings, text};
+
+// 비교 대상: 기록 파일의 snapshot 또는 kubeconfig context
+enum Source {
+    Recording { path: String, index: Option<i64> },
+    Context(String),
+}
+
+impl Source {
+    // 존재하는 파일이면 기록, kubeconfig의 context 이름과 같으면 context
+    // 파일처럼 보이는데("@N", .jsonl/.json, 경로 구분자) 없으면 context로 넘기지 않고 에러
+    fn parse(spec: &str) -> Result<Self, AppError> {
+        let indexed = spec
+            .rsplit_once('@')
+            .and_then(|(path, index)| index.parse::<i64>().ok().map(|index| (path, index)));
+        if let Some((path, index)) = indexed {
+            if Path::new(path).is_file() {
+                return Ok(Source::Recording { path: path.to_string(), index: Some(index) });
+            }
+        }
+        if Path::new(spec).is_file() {
+            return Ok(Source::Recording { path: spec.to_string(), index: None });
+        }
+
+        // EKS처럼 '/'가 들어가는 context 이름도 있으므로 context를 먼저 확인
+        let contexts: Vec<String> = Kubeconfig::read()
+            .map(|config| config.contexts.into_iter().map(|context| context.name).collect())
+            .unwrap_or_default();
+        if contexts.iter().any(|context| context == spec) {
+            return Ok(Source::Context(spec.to_string()));
+        }
+
+        let path = indexed.map_or(spec, |(path, _)| path);
+        let looks_like_file = indexed.is_some()
+            || path.ends_with(".jsonl")
+            || path.ends_with(".json")
+            || path.contains(['/', std::path::MAIN_SEPARATOR]);
+        if looks_like_file {
+            return Err(AppError::IoError(format!("{}: no such file", path)));
+        }
+        Err(AppError::ParseError(format!(
+            "'{}' is neither a recording file nor a kubeconfig context (contexts: {})",
+            spec,
+            if contexts.is_empty() { "none".to_string() } else { contexts.join(", ") }
+        )))
+    }
+
+    // 비교할 snapshot과 출력용 설명
+    async fn load(&self, scope: &ScopeConfig, request: RequestConfig) -> Result<(Snapshot, String), AppError> {
+        match self {
+            Source::Recording { path, index } => {
+                let mut snapshots = snapshot::load(Path::new(path))?;
+                let count = snapshots.len() as i64;
+                // N은 1부터, 음수는 뒤에서부터 (-1이 마지막)
+                let position = match index {
+                    None => count - 1,
+                    Some(index) if *index > 0 => index - 1,
+                    Some(index) => count + index,
+                };
+                if !(0..count).contains(&position) {
+                    return Err(AppError::ParseError(format!(
+                        "{}: snapshot {} out of range (1..={} or -{}..=-1)",
+                        path,
+                        index.unwrap_or_default(),
+                        count,
+                        count
+                    )));
+                }
+                let snapshot = snapshots.swap_remove(position as usize);
+                let label = format!("{} [{}/{}]", path, position + 1, count);
+                Ok((snapshot, label))
+            }
+            Source::Context(name) => {
+                let ctx = KubeContext::for_context(name, scope.clone(), request).await?;
+                let (usage, warnings) = fetch_cluster_usage(&ctx).await?;
+                print_warnings(&warnings);
+                Ok((Snapshot::new(usage), format!("context {}", name)))
+            }
+        }
+    }
+}
+
+// 행 하나의 리소스 합계 (노드는 allocatable, 워크로드는 Pod 수 포함)
+#[derive(Default, Clone)]
+struct Totals {
+    resources: Resources,
+    allocatable_cpu: Cpu,
+    allocatable_memory: Memory,
+    pods: i64,
+}
+
+impl Totals {
+    fn minus(&self, other: &Totals) -> Totals {
+        let mut resources = self.resources.clone();
+        resources.sub(&other.resources);
+        Totals {
+            resources,
+            allocatable_cpu: self.allocatable_cpu - other.allocatable_cpu,
+            allocatable_memory: self.allocatable_memory - other.allocatable_memory,
+            pods: self.pods - other.pods,
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        [self.resources.cpu_request, self.resources.cpu_limit, self.allocatable_cpu]
+            .iter()
+            .all(|cpu| *cpu == Cpu::default())
+            && [self.resources.memory_request, self.resources.memory_limit, self.allocatable_memory]
+                .iter()
+                .all(|memory| *memory == Memory::default())
+            && self.pods == 0
+    }
+
+    // 정렬 기준: 선택한 리소스의 request/limit/allocatable 변화량 중 가장 큰 절댓값
+    fn magnitude(&self, sort: DiffSort) -> u64 {
+        match sort {
+            DiffSort::Cpu => [self.resources.cpu_request, self.resources.cpu_limit, self.allocatable_cpu]
+                .iter()
+                .map(|cpu| cpu.as_nanocores().unsigned_abs())
+                .max()
+                .unwrap_or_default(),
+            DiffSort::Memory => [self.resources.memory_request, self.resources.memory_limit, self.allocatable_memory]
+                .iter()
+                .map(|memory| memory.as_bytes().unsigned_abs())
+                .max()
+                .unwrap_or_default(),
+        }
+    }
+}
+
+struct DiffRow {
+    namespace: String, // 워크로드만 사용
+    name: String,
+    change: &'static str,
+    delta: Totals,
+}
+
+const NAMESPACE_DIFF_COLUMNS: &[ColumnDef<DiffRow>] = &[
+    ColumnDef::text("Namespace", 20, |row| CellValue::Text(row.name.clone())),
+    ColumnDef::text("Change", 10, |row| CellValue::Text(row.change.to_string())),
+    ColumnDef::total("CPU Req. Δ", 15, |row| CellValue::Cpu(row.delta.resources.cpu_request)),
+    ColumnDef::total("CPU Lim. Δ", 15, |row| CellValue::Cpu(row.delta.resources.cpu_limit)),
+    ColumnDef::total("Mem Req. Δ", 15, |row| CellValue::Memory(row.delta.resources.memory_request)),
+    ColumnDef::total("Mem Lim. Δ", 15, |row| CellValue::Memory(row.delta.resources.memory_limit)),
+];
+
+const NODE_DIFF_COLUMNS: &[ColumnDef<DiffRow>] = &[
+    ColumnDef::text("Node", 25, |row| CellValue::Text(row.name.clone())),
+    ColumnDef::text("Change", 10, |row| CellValue::Text(row.change.to_string())),
+    ColumnDef::total("CPU Alloc. Δ", 15, |row| CellValue::Cpu(row.delta.allocatable_cpu)),
+    ColumnDef::total("Mem Alloc. Δ", 15, |row| CellValue::Memory(row.delta.allocatable_memory)),
+    ColumnDef::total("CPU Req. Δ", 15, |row| CellValue::Cpu(row.delta.resources.cpu_request)),
+    ColumnDef::total("CPU Lim. Δ", 15, |row| CellValue::Cpu(row.delta.resources.cpu_limit)),
+    ColumnDef::total("Mem Req. Δ", 15, |row| CellValue::Memory(row.delta.resources.memory_request)),
+    ColumnDef::total("Mem Lim. Δ", 15, |row| CellValue::Memory(row.delta.resources.memory_limit)),
+];
+
+const WORKLOAD_DIFF_COLUMNS: &[ColumnDef<DiffRow>] = &[
+    ColumnDef::text("Namespace", 20, |row| CellValue::Text(row.namespace.clone())),
+    ColumnDef::text("Workload", 35, |row| CellValue::Text(row.name.clone())),
+    ColumnDef::text("Change", 10, |row| CellValue::Text(row.change.to_string())),
+    ColumnDef::total("Pods Δ", 8, |row| CellValue::Count(row.delta.pods)),
+    ColumnDef::total("CPU Req. Δ", 15, |row| CellValue::Cpu(row.delta.resources.cpu_request)),
+    ColumnDef::total("CPU Lim. Δ", 15, |row| CellValue::Cpu(row.delta.resources.cpu_limit)),
+    ColumnDef::total("Mem Req. Δ", 15, |row| CellValue::Memory(row.delta.resources.memory_request)),
+    ColumnDef::total("Mem Lim. Δ", 15, |row| CellValue::Memory(row.delta.resources.memory_limit)),
+];
+
+type Key = (String, String); // (namespace, name), namespace는 워크로드만 사용
+
+fn namespace_totals(snapshot: &Snapshot) -> BTreeMap<Key, Totals> {
+    snapshot
+        .namespaces
+        .iter()
+        .map(|row| {
+            let totals = Totals { resources: row.resources.clone(), ..Default::default() };
+            ((String::new(), row.name.clone()), totals)
+        })
+        .collect()
+}
+
+fn node_totals(snapshot: &Snapshot) -> BTreeMap<Key, Totals> {
+    snapshot
+        .nodes
+        .rows
+        .iter()
+        .map(|row| {
+            let totals = Totals {
+                resources: row.resources.base.clone(),
+                allocatable_cpu: row.resources.allocatable_cpu,
+                allocatable_memory: row.resources.allocatable_memory,
+                pods: 0,
+            };
+            ((String::new(), row.name.clone()), totals)
+        })
+        .collect()
+}
+
+// Pod를 소유 워크로드별로 합산 (워크로드가 없는 이전 기록은 Pod 단위)
+// namespace/노드 합계와 같이 완료된 Pod는 제외
+fn workload_totals(snapshot: &Snapshot) -> BTreeMap<Key, Totals> {
+    let mut totals: BTreeMap<Key, Totals> = BTreeMap::new();
+    for pod in snapshot.pods.iter().filter(|pod| pod.counts_toward_requests()) {
+        let workload = if pod.workload.is_empty() { format!("Pod/{}", pod.name) } else { pod.workload.clone() };
+        let entry = totals.entry((pod.namespace.clone(), workload)).or_default();
+        entry.resources.add(&pod.resources);
+        entry.pods += 1;
+    }
+    totals
+}
+
+// 추가/삭제/변경된 행만, 변화량이 큰 순서로
+fn diff_rows(before: &BTreeMap<Key, Totals>, after: &BTreeMap<Key, Totals>, sort: DiffSort) -> Vec<DiffRow> {
+    let keys: BTreeSet<&Key> = before.keys().chain(after.keys()).collect();
+    let mut rows: Vec<DiffRow> = keys
+        .into_iter()
+        .filter_map(|key| {
+            let (change, delta) = match (before.get(key), after.get(key)) {
+                (None, Some(new)) => ("added", new.clone()),
+                (Some(old), None) => ("removed", Totals::default().minus(old)),
+                (Some(old), Some(new)) => ("changed", new.minus(old)),
+                (None, None) => return None,
+            };
+            if change == "changed" && delta.is_zero() {
+                return None;
+            }
+            Some(DiffRow { namespace: key.0.clone(), name: key.1.clone(), change, delta })
+        })
+        .collect();
+
+    let other = match sort {
+        DiffSort::Cpu => DiffSort::Memory,
+        DiffSort::Memory => DiffSort::Cpu,
+    };
+    // stable sort이므로 같은 변화량은 이름순 유지
+    rows.sort_by(|a, b| {
+        b.delta
+            .magnitude(sort)
+            .cmp(&a.delta.magnitude(sort))
+            .then_with(|| b.delta.magnitude(other).cmp(&a.delta.magnitude(other)))
+    });
+    rows
+}
+
+fn print_section(title: &str, rows: &[DiffRow], columns: &[ColumnDef<DiffRow>]) {
+    let count = |change: &str| rows.iter().filter(|row| row.change == change).count();
+    println!(
+        "\n{}: {} added, {} removed, {} changed",
+        title,
+        count("added"),
+        count("removed"),
+        count("changed")
+    );
+    if !rows.is_empty() {
+        // 마지막 TOTAL 행은 전체 변화량
+        println!("{}", text::render(&Table::build(rows, columns, None, None), true));
+    }
+}
+
+pub async fn run(args: &DiffArgs, scope: ScopeConfig, request: RequestConfig) -> Result<(), AppError> {
+    // 조회 전에 두 인자를 모두 확인
+    let (before, after) = (Source::parse(&args.before)?, Source::parse(&args.after)?);
+    let (before, before_label) = before.load(&scope, request).await?;
+    let (after, after_label) = after.load(&scope, request).await?;
+
+    println!("Before: {} ({})", before_label, before.time.format("%Y-%m-%d %H:%M:%S"));
+    println!("After:  {} ({})", after_label, after.time.format("%Y-%m-%d %H:%M:%S"));
+    if before.nodes.allocatable_hidden || after.nodes.allocatable_hidden {
+        println!("Note: node allocatable is unknown on one side (no permission to list nodes)");
+    }
+
+    print_section(
+        "Namespaces",
+        &diff_rows(&namespace_totals(&before), &namespace_totals(&after), args.sort),
+        NAMESPACE_DIFF_COLUMNS,
+    );
+    print_section(
+        "Nodes",
+        &diff_rows(&node_totals(&before), &node_totals(&after), args.sort),
+        NODE_DIFF_COLUMNS,
+    );
+    print_section(
+        "Workloads",
+        &diff_rows(&workload_totals(&before), &workload_totals(&after), args.sort),
+        WORKLOAD_DIFF_COLUMNS,
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::dataset::ClusterUsage;
+    use crate::api::namespace::NamespaceRow;
+    use crate::api::node::{build_node_data, node_table};
+    use crate::api::pod::{PodRow, RequestTotals};
+    use crate::output::report::summary_items;
+    use crate::util::common::format_cpu;
+
+    fn pod(name: &str, node: &str, status: &str, millicores: i64) -> PodRow {
+        PodRow {
+            namespace: "default".to_string(),
+            name: name.to_string(),
+            status: status.to_string(),
+            node: node.to_string(),
+            resources: Resources { cpu_request: Cpu::from_nanocores(millicores * 1_000_000), ..Default::default() },
+            priority_class: String::new(),
+            priority: None,
+            workload: format!("Pod/{}", name),
+        }
+    }
+
+    // fetch_cluster_usage와 같은 방식으로 합계 계산 (노드 목록 없이 Pod가 있는 노드만)
+    fn usage(pods: Vec<PodRow>) -> ClusterUsage {
+        let mut totals = RequestTotals::default();
+        for pod in &pods {
+            totals.add(pod);
+        }
+        let nodes = build_node_data(None, &totals.by_node, &mut Vec::new());
+        let namespaces = totals
+            .by_namespace
+            .into_iter()
+            .map(|(name, resources)| NamespaceRow { name, resources })
+            .collect();
+        ClusterUsage { nodes, namespaces, pods }
+    }
+
+    #[test]
+    fn test_completed_pods_excluded_everywhere() {
+        let usage = usage(vec![
+            pod("web-1", "n1", "Running", 500),
+            pod("web-2", "n1", "Running", 500),
+            pod("api", "n2", "Running", 250),
+            pod("migrate", "n2", "Succeeded", 2000),
+            pod("crashed", "n1", "Failed", 1000),
+        ]);
+        let expected = Cpu::from_nanocores(1_250_000_000);
+
+        // Node 뷰 TOTAL의 CPU Req.
+        let node_total = node_table(&usage.nodes, None, None).total;
+        assert_eq!(node_total[3], CellValue::Cpu(expected));
+
+        // report 요약 (Pod 수도 완료된 Pod 제외)
+        let summary = summary_items(&ScopeConfig::new(Vec::new()), &usage, 0).join("\n");
+        assert!(summary.contains(&format!("{} requested", format_cpu(expected))), "{}", summary);
+        assert!(summary.contains("pods: 3"), "{}", summary);
+
+        // diff의 namespace/워크로드 합계
+        let snapshot = Snapshot::new(usage);
+        for totals in [namespace_totals(&snapshot), workload_totals(&snapshot)] {
+            let sum: Cpu = totals.values().map(|totals| totals.resources.cpu_request).sum();
+            assert_eq!(sum, expected);
+        }
+        assert_eq!(workload_totals(&snapshot).len(), 3);
+    }
+}
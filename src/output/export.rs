@@ -0,0 +1,152 @@
+use chrono::{DateTime, Local};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::models::app::AppState;
+use crate::models::cli::Units;
+use crate::models::error::AppError;
+use crate::output::{delimited, markdown, structured, ViewOptions};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    Markdown,
+}
+
+impl ExportFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+            ExportFormat::Markdown => "md",
+        }
+    }
+}
+
+// 화면에 보이는 테이블(필터, 정렬, 컬럼 그대로)을 현재 디렉터리에 파일로 저장
+pub fn export_view(app_state: &AppState, format: ExportFormat) -> Result<PathBuf, AppError> {
+    let now = Local::now();
+    let content = render_view(app_state, format, now)?;
+    let kind = if app_state.show_warnings { "warnings" } else { app_state.view_mode.name() };
+    let file_name = format!("kube-rm-{}-{}.{}", kind, now.format("%Y%m%d-%H%M%S"), format.extension());
+    let path = std::env::current_dir()?.join(file_name);
+    fs::write(&path, content).map_err(|e| AppError::IoError(format!("Failed to write {}: {}", path.display(), e)))?;
+    Ok(path)
+}
+
+// 내보낼 파일 내용
+fn render_view(app_state: &AppState, format: ExportFormat, now: DateTime<Local>) -> Result<String, AppError> {
+    let kind = if app_state.show_warnings { "warnings" } else { app_state.view_mode.name() };
+    let table = &app_state.table;
+    let mut options = ViewOptions::describe(table, app_state.sort_config, app_state.search_config);
+    if let Some(search) = app_state.text_search.describe() {
+        options.filter = Some(match options.filter {
+            Some(filter) => format!("{}, search {}", filter, search),
+            None => format!("search {}", search),
+        });
+    }
+    // 경고 목록에는 TOTAL 행이 없음
+    let include_total = !app_state.show_warnings;
+
+    let content = match format {
+        ExportFormat::Csv => delimited::render(table, b',', Units::Raw, include_total)?,
+        ExportFormat::Json => {
+            let warnings = if app_state.show_warnings { &[][..] } else { &app_state.warnings[..] };
+            let document = structured::render(
+                structured::Format::Json,
+                kind,
+                &options,
+                table,
+                app_state.summary.clone(),
+                warnings,
+            )?;
+            format!("{}\n", document)
+        }
+        ExportFormat::Markdown => {
+            let mut out = format!("# kube-rm {}\n\n", kind);
+            out.push_str(&format!("- Exported: {}\n", now.format("%Y-%m-%d %H:%M:%S")));
+            if let Some(sort) = &options.sort {
+                out.push_str(&format!("- Sort: {}\n", markdown::escape(sort)));
+            }
+            if let Some(filter) = &options.filter {
+                out.push_str(&format!("- Filter: {}\n", markdown::escape(filter)));
+            }
+            if let Some(summary) = &app_state.summary {
+                out.push_str(&format!("- {}\n", markdown::escape(summary)));
+            }
+            out.push('\n');
+            out.push_str(&markdown::render(table, include_total));
+            out
+        }
+    };
+    Ok(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::app::ViewMode;
+    use crate::models::resource::{Cpu, Memory};
+    use crate::models::table::{CellValue, ColumnDef, Table};
+    use chrono::TimeZone;
+
+    const COLUMNS: &[ColumnDef<(&str, Cpu, Memory)>] = &[
+        ColumnDef::text("Pod Name", 20, |row| CellValue::Text(row.0.to_string())),
+        ColumnDef::total("CPU Req.", 15, |row| CellValue::Cpu(row.1)),
+        ColumnDef::total("Mem Req.", 15, |row| CellValue::Memory(row.2)),
+    ];
+
+    // '/api'로 검색한 Pod 뷰 (rebuild_rows가 검색에 일치하는 행만 남김)
+    fn app_state() -> AppState {
+        let rows = [
+            ("api-1", Cpu::from_nanocores(100_000_000), Memory::from_bytes(1024)),
+            ("web-1", Cpu::from_nanocores(200_000_000), Memory::from_bytes(2048)),
+            ("api-2", Cpu::from_nanocores(300_000_000), Memory::from_bytes(4096)),
+        ];
+        let mut app_state = AppState::new();
+        app_state.view_mode = ViewMode::Pod;
+        for c in "api".chars() {
+            app_state.text_search.push(c);
+        }
+        app_state.table = Table::build(&rows, COLUMNS, None, None);
+        app_state.rebuild_rows();
+        app_state.summary = Some("2 pods".to_string());
+        app_state
+    }
+
+    fn now() -> DateTime<Local> {
+        Local.with_ymd_and_hms(2024, 5, 1, 12, 30, 0).unwrap()
+    }
+
+    #[test]
+    fn test_export_csv_contains_visible_rows() {
+        let output = render_view(&app_state(), ExportFormat::Csv, now()).unwrap();
+        assert_eq!(output, "Pod Name,CPU Req. (m),Mem Req. (bytes)\napi-1,100,1024\napi-2,300,4096\nTOTAL,400,5120\n");
+    }
+
+    #[test]
+    fn test_export_json_and_markdown_describe_view() {
+        let output = render_view(&app_state(), ExportFormat::Json, now()).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(json["kind"], "pods");
+        assert_eq!(json["sort"], "Pod Name");
+        assert_eq!(json["filter"], "search /api/ (substring)");
+        assert_eq!(json["items"].as_array().unwrap().len(), 2);
+        assert_eq!(json["totals"]["mem_req"]["bytes"], 5120);
+        assert_eq!(json["summary"], "2 pods");
+
+        let output = render_view(&app_state(), ExportFormat::Markdown, now()).unwrap();
+        let expected = "# kube-rm pods\n\n\
+            - Exported: 2024-05-01 12:30:00\n\
+            - Sort: Pod Name\n\
+            - Filter: search /api/ (substring)\n\
+            - 2 pods\n\n\
+            | Pod Name | CPU Req. | Mem Req. |\n\
+            | --- | ---: | ---: |\n\
+            | api-1 | 100m | 1Ki |\n\
+            | api-2 | 300m | 4Ki |\n\
+            | TOTAL | 400m | 5Ki |\n";
+        assert_eq!(output, expected);
+    }
+}
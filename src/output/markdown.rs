@@ -0,0 +1,22 @@
+use crate::models::table::{Align, Table};
+
+// 표 구분자와 겹치지 않도록 '|' escape
+pub fn escape(value: &str) -> String {
+    value.replace('|', "\\|")
+}
+
+// GitHub-flavored Markdown 표 (숫자 컬럼은 오른쪽 정렬)
+pub fn render(table: &Table, include_total: bool) -> String {
+    let row = |cells: Vec<String>| format!("| {} |\n", cells.join(" | "));
+    let mut out = row(table.headers.iter().map(|header| escape(header)).collect());
+    out.push_str(&row(table
+        .aligns
+        .iter()
+        .map(|align| if *align == Align::Right { "---:" } else { "---" }.to_string())
+        .collect()));
+    let total = include_total.then_some(&table.total);
+    for cells in table.rows.iter().chain(total) {
+        out.push_str(&row(cells.iter().map(|cell| escape(&cell.to_string())).collect()));
+    }
+    out
+}
@@ -0,0 +1,190 @@
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use std::convert::Infallible;
+use std::fmt::Write;
+use std::net::SocketAddr;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::time::MissedTickBehavior;
+
+use crate::api::context::KubeContext;
+use crate::api::dataset::{fetch_cluster_usage, ClusterUsage};
+use crate::api::namespace::NamespaceRow;
+use crate::api::node::NodeRow;
+use crate::models::cli::ServeArgs;
+use crate::models::error::AppError;
+use crate::models::resource::Memory;
+use crate::util::common::format_cores;
+
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+// 마지막 조회 상태 (조회가 실패하면 이전에 성공한 gauge를 유지)
+#[derive(Default)]
+struct Snapshot {
+    usage: String,              // 마지막으로 성공한 조회의 node/namespace gauge
+    success: Option<bool>,      // 아직 조회가 끝나지 않았으면 None
+    last_success: Option<f64>,  // unix time (초)
+    duration: f64,              // 마지막 조회 소요 시간 (초)
+    warnings: usize,            // 파싱할 수 없었던 quantity 개수
+}
+
+// label 값 escape (Prometheus text format)
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+fn write_family(out: &mut String, name: &str, help: &str, label: &str, samples: &[(&str, String)]) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} gauge", name);
+    for (label_value, value) in samples {
+        let _ = writeln!(out, "{}{{{}=\"{}\"}} {}", name, label, escape_label(label_value), value);
+    }
+}
+
+fn write_gauge(out: &mut String, name: &str, help: &str, value: String) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} gauge", name);
+    let _ = writeln!(out, "{} {}", name, value);
+}
+
+fn bytes(memory: Memory) -> String {
+    memory.as_bytes().to_string()
+}
+
+// Node/Namespace 뷰와 같은 합계를 gauge로 변환
+fn render_usage(usage: &ClusterUsage) -> String {
+    let mut out = String::new();
+    let nodes = &usage.nodes.rows;
+    let node_samples = |value: fn(&NodeRow) -> String| -> Vec<(&str, String)> {
+        nodes.iter().map(|row| (row.name.as_str(), value(row))).collect()
+    };
+
+    // 노드 조회 권한이 없으면 allocatable은 내보내지 않음
+    if !usage.nodes.allocatable_hidden {
+        write_family(&mut out, "kube_rm_node_allocatable_cpu_cores", "Allocatable CPU of the node.", "node",
+            &node_samples(|row| format_cores(row.resources.allocatable_cpu)));
+        write_family(&mut out, "kube_rm_node_allocatable_memory_bytes", "Allocatable memory of the node.", "node",
+            &node_samples(|row| bytes(row.resources.allocatable_memory)));
+    }
+    write_family(&mut out, "kube_rm_node_cpu_requests_cores", "Sum of CPU requests of pods on the node.", "node",
+        &node_samples(|row| format_cores(row.resources.base.cpu_request)));
+    write_family(&mut out, "kube_rm_node_cpu_limits_cores", "Sum of CPU limits of pods on the node.", "node",
+        &node_samples(|row| format_cores(row.resources.base.cpu_limit)));
+    write_family(&mut out, "kube_rm_node_memory_requests_bytes", "Sum of memory requests of pods on the node.", "node",
+        &node_samples(|row| bytes(row.resources.base.memory_request)));
+    write_family(&mut out, "kube_rm_node_memory_limits_bytes", "Sum of memory limits of pods on the node.", "node",
+        &node_samples(|row| bytes(row.resources.base.memory_limit)));
+
+    let namespaces = &usage.namespaces;
+    let namespace_samples = |value: fn(&NamespaceRow) -> String| -> Vec<(&str, String)> {
+        namespaces.iter().map(|row| (row.name.as_str(), value(row))).collect()
+    };
+    write_family(&mut out, "kube_rm_namespace_cpu_requests_cores", "Sum of CPU requests of pods in the namespace.", "namespace",
+        &namespace_samples(|row| format_cores(row.resources.cpu_request)));
+    write_family(&mut out, "kube_rm_namespace_cpu_limits_cores", "Sum of CPU limits of pods in the namespace.", "namespace",
+        &namespace_samples(|row| format_cores(row.resources.cpu_limit)));
+    write_family(&mut out, "kube_rm_namespace_memory_requests_bytes", "Sum of memory requests of pods in the namespace.", "namespace",
+        &namespace_samples(|row| bytes(row.resources.memory_request)));
+    write_family(&mut out, "kube_rm_namespace_memory_limits_bytes", "Sum of memory limits of pods in the namespace.", "namespace",
+        &namespace_samples(|row| bytes(row.resources.memory_limit)));
+    out
+}
+
+fn render(snapshot: &Snapshot) -> String {
+    let mut out = snapshot.usage.clone();
+    if let Some(success) = snapshot.success {
+        write_gauge(&mut out, "kube_rm_last_fetch_success", "Whether the last fetch from the API server succeeded.",
+            (success as u8).to_string());
+        write_gauge(&mut out, "kube_rm_last_fetch_duration_seconds", "Duration of the last fetch from the API server.",
+            format!("{:.3}", snapshot.duration));
+    }
+    if let Some(last_success) = snapshot.last_success {
+        write_gauge(&mut out, "kube_rm_last_success_timestamp_seconds", "Unix time of the last successful fetch.",
+            format!("{:.3}", last_success));
+        write_gauge(&mut out, "kube_rm_parse_warnings", "Quantities that could not be parsed and were counted as 0.",
+            snapshot.warnings.to_string());
+    }
+    out
+}
+
+async fn collect(ctx: &KubeContext, state: &RwLock<Snapshot>) {
+    let started = Instant::now();
+    let result = fetch_cluster_usage(&ctx.for_fetch()).await;
+    let duration = started.elapsed().as_secs_f64();
+
+    // 조회가 끝난 뒤에 렌더링하여 lock은 짧게 유지
+    let rendered = result.map(|(usage, warnings)| (render_usage(&usage), warnings.len()));
+    let mut snapshot = state.write().unwrap();
+    snapshot.duration = duration;
+    match rendered {
+        Ok((usage, warnings)) => {
+            snapshot.usage = usage;
+            snapshot.warnings = warnings;
+            snapshot.success = Some(true);
+            snapshot.last_success = SystemTime::now().duration_since(UNIX_EPOCH).ok().map(|d| d.as_secs_f64());
+        }
+        Err(e) => {
+            snapshot.success = Some(false);
+            eprintln!("fetch failed ({}): {}", e.variant_name(), e);
+        }
+    }
+}
+
+fn respond(request: &Request<Body>, state: &RwLock<Snapshot>) -> Response<Body> {
+    let (status, content_type, body) = match (request.method(), request.uri().path()) {
+        (&Method::GET, "/metrics") => (StatusCode::OK, CONTENT_TYPE, render(&state.read().unwrap())),
+        (&Method::GET, "/") => (StatusCode::OK, "text/html; charset=utf-8",
+            "<html><body><a href=\"/metrics\">/metrics</a></body></html>\n".to_string()),
+        _ => (StatusCode::NOT_FOUND, "text/plain; charset=utf-8", "not found\n".to_string()),
+    };
+    let mut response = Response::new(Body::from(body));
+    *response.status_mut() = status;
+    response
+        .headers_mut()
+        .insert(hyper::header::CONTENT_TYPE, hyper::header::HeaderValue::from_static(content_type));
+    response
+}
+
+// ":9100"처럼 host가 없으면 모든 interface에서 listen
+fn listen_address(listen: &str) -> Result<SocketAddr, AppError> {
+    let address = if listen.starts_with(':') { format!("0.0.0.0{}", listen) } else { listen.to_string() };
+    address
+        .parse()
+        .map_err(|_| AppError::ParseError(format!("Invalid listen address '{}', expected HOST:PORT or :PORT", listen)))
+}
+
+// headless 모드: 주기적으로 조회하고 /metrics로 노출
+pub async fn serve(ctx: &KubeContext, args: &ServeArgs) -> Result<(), AppError> {
+    let address = listen_address(&args.listen)?;
+    let server = Server::try_bind(&address)
+        .map_err(|e| AppError::IoError(format!("Failed to listen on {}: {}", address, e)))?;
+
+    let state = Arc::new(RwLock::new(Snapshot::default()));
+    let collector_ctx = ctx.clone();
+    let collector_state = state.clone();
+    let interval = Duration::from_secs(args.interval.max(1));
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            collect(&collector_ctx, &collector_state).await;
+        }
+    });
+
+    let make_service = make_service_fn(move |_| {
+        let state = state.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                let response = respond(&request, &state);
+                async move { Ok::<_, Infallible>(response) }
+            }))
+        }
+    });
+
+    eprintln!("Serving metrics on http://{}/metrics (refresh every {}s)", address, interval.as_secs());
+    server
+        .serve(make_service)
+        .await
+        .map_err(|e| AppError::IoError(format!("Metrics server failed: {}", e)))
+}
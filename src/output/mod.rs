@@ -0,0 +1,177 @@
+pub mod check;
+pub mod delimited;
+pub mod diff;
+pub mod export;
+pub mod markdown;
+pub mod metrics;
+pub mod report;
+pub mod structured;
+pub mod text;
+pub mod watch;
+
+use crate::api::context::KubeContext;
+use std::path::Path;
+
+use crate::api::dataset::{column_headers, fetch_cluster_usage, fetch_dataset};
+use crate::api::snapshot::{self, Snapshot};
+use crate::models::app::ViewMode;
+use crate::models::cli::{Command, ListCommand, OutputFormat, PodsArgs};
+use crate::models::config::{SearchConfig, SortConfig};
+use crate::models::error::AppError;
+use crate::models::table::Table;
+use crate::models::warning::ParseWarning;
+
+// 헤더 비교용: 영문/숫자만 소문자로 ("CPU Req." == "cpu-req" == "cpureq")
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+// 컬럼 이름(헤더) 또는 0부터 시작하는 인덱스를 컬럼 인덱스로 변환
+pub fn resolve_column(headers: &[&str], name: &str) -> Result<usize, AppError> {
+    if let Ok(index) = name.trim().parse::<usize>() {
+        if index < headers.len() {
+            return Ok(index);
+        }
+    }
+
+    let wanted = normalize(name);
+    headers
+        .iter()
+        .position(|header| normalize(header) == wanted)
+        .ok_or_else(|| {
+            let available: Vec<String> = headers.iter().map(|header| normalize(header)).collect();
+            AppError::ParseError(format!("Unknown column '{}' (available: {})", name, available.join(", ")))
+        })
+}
+
+// 출력에 함께 기록하는 정렬/필터 설명 ("CPU Req.", "Node=worker-1")
+#[derive(Debug, Clone, Default)]
+pub struct ViewOptions {
+    pub sort: Option<String>,
+    pub filter: Option<String>,
+}
+
+impl ViewOptions {
+    pub fn describe(table: &Table, sort_config: SortConfig, search_config: SearchConfig) -> Self {
+        Self {
+            sort: table.headers.get(sort_config.column).map(|header| header.to_string()),
+            filter: table
+                .headers
+                .get(search_config.column)
+                .map(|header| format!("{}={}", header, search_config.get_word())),
+        }
+    }
+}
+
+// --record는 Node/Pod/Namespace 데이터를 조회하는 커맨드에서만 사용 (조회 전에 확인)
+pub fn check_record(command: &Command) -> Result<(), AppError> {
+    let supported = match command {
+        Command::List(ListCommand::Pods(PodsArgs { watch: true, .. })) => false,
+        Command::List(command) => matches!(command.view_mode(), ViewMode::Node | ViewMode::Pod | ViewMode::Namespace),
+        Command::Report(_) | Command::Serve(_) | Command::Check(_) => true,
+        Command::Diff(_) => false,
+    };
+    if supported {
+        Ok(())
+    } else {
+        Err(AppError::ParseError(
+            "--record works only with the UI, nodes, pods (without --watch), namespaces, report, check and serve".to_string(),
+        ))
+    }
+}
+
+// 비대화형 모드: 서브커맨드 결과를 stdout으로 출력
+// record가 있으면 커맨드가 조회한 데이터를 snapshot으로 추가 (serve는 main에서 주기적으로 기록)
+pub async fn run_command(ctx: &KubeContext, command: &Command, record: Option<&Path>) -> Result<(), AppError> {
+    match command {
+        Command::List(command) => run_list(ctx, command, record).await,
+        Command::Report(args) => report::run(ctx, args, record).await,
+        Command::Serve(args) => metrics::serve(ctx, args).await,
+        Command::Diff(args) => diff::run(args, ctx.scope.clone(), ctx.request).await,
+        Command::Check(args) => check::run(ctx, args, record).await,
+    }
+}
+
+// 뷰와 같은 테이블을 출력
+async fn run_list(ctx: &KubeContext, command: &ListCommand, record: Option<&Path>) -> Result<(), AppError> {
+    let view_mode = command.view_mode();
+    let args = command.list_args();
+    let headers = column_headers(view_mode);
+
+    // 조회 전에 --sort/--filter 컬럼 확인
+    let sort_config = match &args.sort {
+        Some(column) => SortConfig::new(resolve_column(&headers, column)?),
+        None => SortConfig::new(0),
+    };
+    let search_config = match &args.filter {
+        Some(filter) => {
+            let (column, value) = filter.split_once('=').ok_or_else(|| {
+                AppError::ParseError(format!("Invalid filter '{}', expected COLUMN=VALUE", filter))
+            })?;
+            SearchConfig::new(resolve_column(&headers, column)?, value)
+        }
+        None => SearchConfig::new(999, ""),
+    };
+
+    if let ListCommand::Pods(PodsArgs { watch: true, .. }) = command {
+        return watch::watch_pods(ctx, args.output, search_config, sort_config).await;
+    }
+
+    let (dataset, warnings) = match record {
+        // 기록할 때는 Node/Pod/Namespace를 한 번에 조회하고 뷰의 데이터는 snapshot에서 가져옴
+        Some(path) => {
+            let (usage, warnings) = fetch_cluster_usage(ctx).await?;
+            let snapshot = Snapshot::new(usage);
+            snapshot::append(path, &snapshot)?;
+            let dataset = snapshot.dataset(view_mode).ok_or_else(|| {
+                AppError::ParseError(format!("--record is not supported for {}", view_mode.name()))
+            })?;
+            (dataset, warnings)
+        }
+        None => fetch_dataset(ctx, view_mode).await?,
+    };
+    let table = dataset.to_table(search_config, sort_config);
+
+    match args.output {
+        OutputFormat::Table => println!("{}", text::render(&table, true)),
+        OutputFormat::Ndjson => watch::print_snapshot(view_mode.name(), &table)?,
+        OutputFormat::Csv | OutputFormat::Tsv => {
+            let delimiter = if args.output == OutputFormat::Csv { b',' } else { b'\t' };
+            print!("{}", delimited::render(&table, delimiter, args.units, !args.no_total)?);
+        }
+        // JSON/YAML은 요약과 경고를 문서 안에 포함
+        OutputFormat::Json | OutputFormat::Yaml => {
+            let format = if args.output == OutputFormat::Json { structured::Format::Json } else { structured::Format::Yaml };
+            let options = ViewOptions::describe(&table, sort_config, search_config);
+            let document = structured::render(format, view_mode.name(), &options, &table, dataset.summary(), &warnings)?;
+            println!("{}", document.trim_end());
+            return Ok(());
+        }
+    }
+
+    // 요약과 경고는 파이프로 넘기는 출력과 섞이지 않도록 stderr로
+    if let Some(summary) = dataset.summary() {
+        eprintln!("{}", summary);
+    }
+    print_warnings(&warnings);
+
+    Ok(())
+}
+
+pub fn print_warnings(warnings: &[ParseWarning]) {
+    for warning in warnings {
+        eprintln!(
+            "warning: {} {}/{}{} {}={:?}: {}",
+            warning.kind,
+            warning.namespace,
+            warning.name,
+            if warning.container.is_empty() { String::new() } else { format!(" container {}", warning.container) },
+            warning.field,
+            warning.value,
+            warning.message,
+        );
+    }
+}
@@ -0,0 +1,327 @@
+use chrono::Local;
+use std::path::Path;
+
+use crate::api::context::KubeContext;
+use crate::api::dataset::{fetch_cluster_usage, ClusterUsage};
+use crate::api::namespace::{NamespaceRow, NAMESPACE_COLUMNS};
+use crate::api::snapshot;
+use crate::api::node::{node_table, NodeData};
+use crate::api::pod::PodRow;
+use crate::models::cli::{ReportArgs, ReportFormat};
+use crate::models::config::{ScopeConfig, SortConfig};
+use crate::models::error::AppError;
+use crate::models::resource::{Cpu, Memory, Resources};
+use crate::models::table::{Align, CellValue, ColumnDef, Table};
+use crate::output::markdown;
+use crate::util::common::{format_count, format_cpu, format_memory, percent};
+
+// request/limit이 없는 Pod 목록의 최대 행 수 (나머지는 개수만 표시)
+const MAX_MISSING_PODS: usize = 100;
+
+// NAMESPACE_COLUMNS의 정렬 기준 컬럼
+const NAMESPACE_CPU_REQUEST: usize = 1;
+const NAMESPACE_MEMORY_REQUEST: usize = 3;
+
+// 문서 구성 요소 (Markdown/HTML 공통)
+enum Block {
+    Heading(String),
+    Paragraph(String),
+    List(Vec<String>),
+    Table { table: Table, total: bool },
+}
+
+// request 또는 limit이 없는 Pod
+struct MissingRow {
+    namespace: String,
+    name: String,
+    node: String,
+    missing: String,
+}
+
+const MISSING_COLUMNS: &[ColumnDef<MissingRow>] = &[
+    ColumnDef::text("Namespace", 20, |row| CellValue::Text(row.namespace.clone())),
+    ColumnDef::text("Pod Name", 35, |row| CellValue::Text(row.name.clone())),
+    ColumnDef::text("Node", 25, |row| CellValue::Text(row.node.clone())),
+    ColumnDef::text("Missing", 40, |row| CellValue::Text(row.missing.clone())),
+];
+
+// allocatable보다 request/limit 합계가 큰 노드
+struct OvercommitRow {
+    node: String,
+    resource: &'static str,
+    allocatable: CellValue,
+    requests: CellValue,
+    limits: CellValue,
+    request_percent: f64,
+    limit_percent: f64,
+}
+
+const OVERCOMMIT_COLUMNS: &[ColumnDef<OvercommitRow>] = &[
+    ColumnDef::text("Node", 25, |row| CellValue::Text(row.node.clone())),
+    ColumnDef::text("Resource", 10, |row| CellValue::Text(row.resource.to_string())),
+    ColumnDef::number("Allocatable", 15, |row| row.allocatable.clone()),
+    ColumnDef::number("Requests", 15, |row| row.requests.clone()),
+    ColumnDef::number("Limits", 15, |row| row.limits.clone()),
+    ColumnDef::number("Req. %", 10, |row| CellValue::Text(format_percent(Some(row.request_percent)))),
+    ColumnDef::number("Lim. %", 10, |row| CellValue::Text(format_percent(Some(row.limit_percent)))),
+];
+
+fn format_percent(value: Option<f64>) -> String {
+    value.map(|value| format!("{:.0}%", value)).unwrap_or_else(|| "-".to_string())
+}
+
+// 완료된 Pod는 리소스를 차지하지 않으므로 제외
+fn missing_row(pod: &PodRow) -> Option<MissingRow> {
+    if !pod.counts_toward_requests() {
+        return None;
+    }
+    let resources = &pod.resources;
+    let missing: Vec<&str> = [
+        (resources.cpu_request == Cpu::default(), "CPU request"),
+        (resources.memory_request == Memory::default(), "memory request"),
+        (resources.cpu_limit == Cpu::default(), "CPU limit"),
+        (resources.memory_limit == Memory::default(), "memory limit"),
+    ]
+    .into_iter()
+    .filter_map(|(is_missing, field)| is_missing.then_some(field))
+    .collect();
+
+    (!missing.is_empty()).then(|| MissingRow {
+        namespace: pod.namespace.clone(),
+        name: pod.name.clone(),
+        node: pod.node.clone(),
+        missing: missing.join(", "),
+    })
+}
+
+fn overcommit_rows(node_data: &NodeData) -> Vec<OvercommitRow> {
+    let mut rows = Vec::new();
+    for node in &node_data.rows {
+        let resources = &node.resources;
+        let cpu = (
+            "CPU",
+            resources.allocatable_cpu.as_nanocores(),
+            resources.base.cpu_request.as_nanocores(),
+            resources.base.cpu_limit.as_nanocores(),
+            CellValue::Cpu(resources.allocatable_cpu),
+            CellValue::Cpu(resources.base.cpu_request),
+            CellValue::Cpu(resources.base.cpu_limit),
+        );
+        let memory = (
+            "Memory",
+            resources.allocatable_memory.as_bytes(),
+            resources.base.memory_request.as_bytes(),
+            resources.base.memory_limit.as_bytes(),
+            CellValue::Memory(resources.allocatable_memory),
+            CellValue::Memory(resources.base.memory_request),
+            CellValue::Memory(resources.base.memory_limit),
+        );
+        for (resource, allocatable, requested, limited, allocatable_cell, requests, limits) in [cpu, memory] {
+            let (Some(request_percent), Some(limit_percent)) = (percent(requested, allocatable), percent(limited, allocatable)) else {
+                continue;
+            };
+            if request_percent > 100.0 || limit_percent > 100.0 {
+                rows.push(OvercommitRow {
+                    node: node.name.clone(),
+                    resource,
+                    allocatable: allocatable_cell,
+                    requests,
+                    limits,
+                    request_percent,
+                    limit_percent,
+                });
+            }
+        }
+    }
+    // limit 비율이 높은 순서로
+    rows.sort_by(|a, b| b.limit_percent.total_cmp(&a.limit_percent));
+    rows
+}
+
+fn top_namespaces(rows: &[NamespaceRow], column: usize, top: usize) -> Table {
+    let mut table = Table::build(rows, NAMESPACE_COLUMNS, None, Some(SortConfig::new(column)));
+    table.rows.truncate(top);
+    table
+}
+
+// request 합계와 Pod 수는 완료된 Pod를 제외한 namespace 합계 기준 (노드 테이블 TOTAL과 같은 기준)
+pub fn summary_items(scope: &ScopeConfig, usage: &ClusterUsage, warnings: usize) -> Vec<String> {
+    let node_data = &usage.nodes;
+    let mut total = Resources::new();
+    for namespace in &usage.namespaces {
+        total.add(&namespace.resources);
+    }
+    let pods = usage.pods.iter().filter(|pod| pod.counts_toward_requests()).count();
+    let allocatable_cpu: Cpu = node_data.rows.iter().map(|row| row.resources.allocatable_cpu).sum();
+    let allocatable_memory: Memory = node_data.rows.iter().map(|row| row.resources.allocatable_memory).sum();
+
+    let scope = if scope.is_cluster_wide() {
+        "cluster-wide".to_string()
+    } else {
+        format!("namespaces {}", scope.namespaces.join(", "))
+    };
+    let mut items = vec![
+        format!("Scope: {}", scope),
+        format!(
+            "Nodes: {}, pods: {}, namespaces: {}",
+            format_count(node_data.rows.len()),
+            format_count(pods),
+            format_count(usage.namespaces.len())
+        ),
+        format!(
+            "CPU: {} allocatable, {} requested ({}), {} limits ({})",
+            format_cpu(allocatable_cpu),
+            format_cpu(total.cpu_request),
+            format_percent(percent(total.cpu_request.as_nanocores(), allocatable_cpu.as_nanocores())),
+            format_cpu(total.cpu_limit),
+            format_percent(percent(total.cpu_limit.as_nanocores(), allocatable_cpu.as_nanocores())),
+        ),
+        format!(
+            "Memory: {} allocatable, {} requested ({}), {} limits ({})",
+            format_memory(allocatable_memory),
+            format_memory(total.memory_request),
+            format_percent(percent(total.memory_request.as_bytes(), allocatable_memory.as_bytes())),
+            format_memory(total.memory_limit),
+            format_percent(percent(total.memory_limit.as_bytes(), allocatable_memory.as_bytes())),
+        ),
+    ];
+    if let Some(summary) = node_data.summary() {
+        items.push(summary);
+    }
+    if warnings > 0 {
+        items.push(format!("{} unparseable quantities were counted as 0", format_count(warnings)));
+    }
+    items
+}
+
+fn build_blocks(ctx: &KubeContext, usage: &ClusterUsage, warnings: usize, top: usize) -> Vec<Block> {
+    let (node_data, namespaces, pods) = (&usage.nodes, &usage.namespaces, &usage.pods);
+    let mut blocks = vec![
+        Block::Heading("Cluster summary".to_string()),
+        Block::List(summary_items(&ctx.scope, usage, warnings)),
+        Block::Heading("Nodes".to_string()),
+        Block::Table { table: node_table(node_data, None, Some(SortConfig::new(0))), total: true },
+        Block::Heading(format!("Top {} namespaces by CPU request", top)),
+        Block::Table { table: top_namespaces(namespaces, NAMESPACE_CPU_REQUEST, top), total: false },
+        Block::Heading(format!("Top {} namespaces by memory request", top)),
+        Block::Table { table: top_namespaces(namespaces, NAMESPACE_MEMORY_REQUEST, top), total: false },
+    ];
+
+    blocks.push(Block::Heading("Overcommit".to_string()));
+    if node_data.allocatable_hidden {
+        blocks.push(Block::Paragraph("Not available: no permission to list nodes.".to_string()));
+    } else {
+        let rows = overcommit_rows(node_data);
+        blocks.push(Block::Paragraph(format!(
+            "{} node resources where requests or limits exceed allocatable.",
+            format_count(rows.len())
+        )));
+        blocks.push(Block::Table { table: Table::build(&rows, OVERCOMMIT_COLUMNS, None, None), total: false });
+    }
+
+    let mut missing: Vec<MissingRow> = pods.iter().filter_map(missing_row).collect();
+    missing.sort_by(|a, b| a.namespace.cmp(&b.namespace).then(a.name.cmp(&b.name)));
+    blocks.push(Block::Heading("Pods without requests or limits".to_string()));
+    let mut note = format!("{} running or pending pods are missing a CPU/memory request or limit.", format_count(missing.len()));
+    if missing.len() > MAX_MISSING_PODS {
+        note.push_str(&format!(" The first {} are listed.", MAX_MISSING_PODS));
+        missing.truncate(MAX_MISSING_PODS);
+    }
+    blocks.push(Block::Paragraph(note));
+    blocks.push(Block::Table { table: Table::build(&missing, MISSING_COLUMNS, None, None), total: false });
+
+    blocks
+}
+
+fn markdown(title: &str, blocks: &[Block]) -> String {
+    let mut out = format!("# {}\n", title);
+    for block in blocks {
+        out.push('\n');
+        match block {
+            Block::Heading(text) => out.push_str(&format!("## {}\n", text)),
+            Block::Paragraph(text) => out.push_str(&format!("{}\n", markdown::escape(text))),
+            Block::List(items) => {
+                for item in items {
+                    out.push_str(&format!("- {}\n", item));
+                }
+            }
+            Block::Table { table, .. } if table.rows.is_empty() => out.push_str("_None_\n"),
+            Block::Table { table, total } => out.push_str(&markdown::render(table, *total)),
+        }
+    }
+    out
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+const HTML_STYLE: &str = "body{font-family:-apple-system,Segoe UI,Helvetica,Arial,sans-serif;margin:2em;color:#222}\
+table{border-collapse:collapse;margin:0.5em 0 1.5em}\
+th,td{border:1px solid #ccc;padding:4px 10px}\
+th{background:#f0f0f0}\
+td.right,th.right{text-align:right}\
+tr.total td{font-weight:bold;background:#fafafa}\
+p.none{color:#888;font-style:italic}";
+
+fn html(title: &str, blocks: &[Block]) -> String {
+    let mut out = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{0}</title>\n<style>{1}</style>\n</head>\n<body>\n<h1>{0}</h1>\n",
+        escape_html(title),
+        HTML_STYLE
+    );
+    for block in blocks {
+        match block {
+            Block::Heading(text) => out.push_str(&format!("<h2>{}</h2>\n", escape_html(text))),
+            Block::Paragraph(text) => out.push_str(&format!("<p>{}</p>\n", escape_html(text))),
+            Block::List(items) => {
+                out.push_str("<ul>\n");
+                for item in items {
+                    out.push_str(&format!("<li>{}</li>\n", escape_html(item)));
+                }
+                out.push_str("</ul>\n");
+            }
+            Block::Table { table, .. } if table.rows.is_empty() => out.push_str("<p class=\"none\">None</p>\n"),
+            Block::Table { table, total } => {
+                let class = |i: usize| if table.aligns[i] == Align::Right { " class=\"right\"" } else { "" };
+                out.push_str("<table>\n<tr>");
+                for (i, header) in table.headers.iter().enumerate() {
+                    out.push_str(&format!("<th{}>{}</th>", class(i), escape_html(header)));
+                }
+                out.push_str("</tr>\n");
+                let total_row = total.then_some(&table.total);
+                for (r, cells) in table.rows.iter().chain(total_row).enumerate() {
+                    out.push_str(if r == table.rows.len() { "<tr class=\"total\">" } else { "<tr>" });
+                    for (i, cell) in cells.iter().enumerate() {
+                        out.push_str(&format!("<td{}>{}</td>", class(i), escape_html(&cell.to_string())));
+                    }
+                    out.push_str("</tr>\n");
+                }
+                out.push_str("</table>\n");
+            }
+        }
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+// 주간 운영 리뷰용 capacity report
+pub async fn run(ctx: &KubeContext, args: &ReportArgs, record: Option<&Path>) -> Result<(), AppError> {
+    let (usage, warnings) = fetch_cluster_usage(ctx).await?;
+    if let Some(path) = record {
+        snapshot::record_usage(path, &usage)?;
+    }
+
+    let title = format!("Kubernetes capacity report ({})", Local::now().format("%Y-%m-%d %H:%M"));
+    let blocks = build_blocks(ctx, &usage, warnings.len(), args.top);
+    let document = match args.format {
+        ReportFormat::Markdown => markdown(&title, &blocks),
+        ReportFormat::Html => html(&title, &blocks),
+    };
+    print!("{}", document);
+    Ok(())
+}
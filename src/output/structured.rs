@@ -0,0 +1,215 @@
+use serde::ser::{SerializeMap, Serializer};
+use serde::Serialize;
+
+use crate::models::error::AppError;
+use crate::models::table::{CellValue, Table};
+use crate::models::warning::ParseWarning;
+use crate::output::ViewOptions;
+
+// 헤더를 snake_case 키로 변환 ("CPU Req. @Max" -> "cpu_req_max")
+pub fn column_key(header: &str) -> String {
+    header
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_ascii_lowercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+// 셀 값: 문자열/개수는 그대로, CPU/Memory는 원시 값과 표시 문자열을 함께
+struct Value<'a>(&'a CellValue);
+
+impl Serialize for Value<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            CellValue::Text(value) => serializer.serialize_str(value),
+            CellValue::Count(value) => serializer.serialize_i64(*value),
+            CellValue::Number(value) => value.serialize(serializer),
+            CellValue::Cpu(cpu) => {
+                let mut map = serializer.serialize_map(Some(3))?;
+                map.serialize_entry("millicores", &cpu.as_millicores())?;
+                map.serialize_entry("nanocores", &cpu.as_nanocores())?;
+                map.serialize_entry("formatted", &self.0.to_string())?;
+                map.end()
+            }
+            CellValue::Memory(memory) => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("bytes", &memory.as_bytes())?;
+                map.serialize_entry("formatted", &self.0.to_string())?;
+                map.end()
+            }
+        }
+    }
+}
+
+// 컬럼 순서를 유지하는 레코드 (값이 없는 컬럼은 제외)
+struct Record<'a> {
+    keys: &'a [String],
+    cells: &'a [CellValue],
+}
+
+impl Serialize for Record<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        for (key, cell) in self.keys.iter().zip(self.cells) {
+            map.serialize_entry(key, &Value(cell))?;
+        }
+        map.end()
+    }
+}
+
+#[derive(Serialize)]
+struct Document<'a> {
+    kind: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<&'a str>,
+    items: Vec<Record<'a>>,
+    totals: Record<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary: Option<String>,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    warnings: &'a [ParseWarning],
+}
+
+// 합계, 변화량처럼 숫자 값만 남긴 레코드 항목 (Text 셀 제외)
+fn numeric_entries(keys: &[String], cells: &[CellValue]) -> (Vec<String>, Vec<CellValue>) {
+    keys.iter()
+        .zip(cells)
+        .filter(|(_, cell)| !matches!(cell, CellValue::Text(_)))
+        .map(|(key, cell)| (key.clone(), cell.clone()))
+        .unzip()
+}
+
+// ndjson 이벤트 (kubectl get -w의 ADDED/MODIFIED/DELETED)
+#[derive(Serialize)]
+struct Event<'a> {
+    #[serde(rename = "type")]
+    change: &'a str,
+    time: &'a str,
+    kind: &'a str,
+    object: Record<'a>,
+    delta: Record<'a>,
+}
+
+// 행 변경 이벤트 한 줄 (delta는 합계 컬럼의 변화량, 나머지 컬럼은 Text)
+pub fn event_line(
+    change: &str,
+    time: &str,
+    kind: &str,
+    keys: &[String],
+    cells: &[CellValue],
+    delta: &[CellValue],
+) -> Result<String, AppError> {
+    let (delta_keys, delta_cells) = numeric_entries(keys, delta);
+    let event = Event {
+        change,
+        time,
+        kind,
+        object: Record { keys, cells },
+        delta: Record { keys: &delta_keys, cells: &delta_cells },
+    };
+    serde_json::to_string(&event).map_err(|e| AppError::ParseError(format!("Failed to serialize JSON: {}", e)))
+}
+
+pub enum Format {
+    Json,
+    Yaml,
+}
+
+pub fn render(
+    format: Format,
+    kind: &str,
+    options: &ViewOptions,
+    table: &Table,
+    summary: Option<String>,
+    warnings: &[ParseWarning],
+) -> Result<String, AppError> {
+    let keys: Vec<String> = table.headers.iter().map(|header| column_key(header)).collect();
+
+    // totals에는 합계가 있는 컬럼만 (첫 컬럼의 "TOTAL" 라벨과 빈 값 제외)
+    let (total_keys, total_cells) = numeric_entries(&keys, &table.total);
+
+    // 표시용 문자열이 아닌, 타입 있는 셀 값 기반의 구조화된 문서
+    let document = Document {
+        kind,
+        sort: options.sort.as_deref(),
+        filter: options.filter.as_deref(),
+        items: table.rows.iter().map(|row| Record { keys: &keys, cells: row }).collect(),
+        totals: Record { keys: &total_keys, cells: &total_cells },
+        summary,
+        warnings,
+    };
+    match format {
+        Format::Json => serde_json::to_string_pretty(&document)
+            .map_err(|e| AppError::ParseError(format!("Failed to serialize JSON: {}", e))),
+        Format::Yaml => serde_yaml::to_string(&document)
+            .map_err(|e| AppError::ParseError(format!("Failed to serialize YAML: {}", e))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::resource::{Cpu, Memory};
+    use crate::models::table::ColumnDef;
+    use serde_json::json;
+
+    const COLUMNS: &[ColumnDef<(&str, Cpu, Memory)>] = &[
+        ColumnDef::text("Pod Name", 20, |row| CellValue::Text(row.0.to_string())),
+        ColumnDef::total("CPU Req. @Max", 15, |row| CellValue::Cpu(row.1)),
+        ColumnDef::total("Mem Req.", 15, |row| CellValue::Memory(row.2)),
+    ];
+
+    fn table() -> Table {
+        let rows = [
+            ("a", Cpu::from_nanocores(250_000_000), Memory::from_bytes(1024)),
+            ("b", Cpu::from_nanocores(500_000), Memory::from_bytes(2048)),
+        ];
+        Table::build(&rows, COLUMNS, None, None)
+    }
+
+    #[test]
+    fn test_column_key() {
+        assert_eq!(column_key("CPU Req. @Max"), "cpu_req_max");
+        assert_eq!(column_key("Pod Name"), "pod_name");
+    }
+
+    #[test]
+    fn test_render_record_shape() {
+        let options = ViewOptions { sort: Some("CPU Req. @Max".to_string()), filter: None };
+        let expected = json!({
+            "kind": "pods",
+            "sort": "CPU Req. @Max",
+            "items": [
+                {
+                    "pod_name": "a",
+                    "cpu_req_max": { "millicores": 250, "nanocores": 250_000_000, "formatted": "250m" },
+                    "mem_req": { "bytes": 1024, "formatted": "1Ki" },
+                },
+                {
+                    "pod_name": "b",
+                    "cpu_req_max": { "millicores": 1, "nanocores": 500_000, "formatted": "500u" },
+                    "mem_req": { "bytes": 2048, "formatted": "2Ki" },
+                },
+            ],
+            // TOTAL 라벨은 제외하고 합계 컬럼만
+            "totals": {
+                "cpu_req_max": { "millicores": 251, "nanocores": 250_500_000, "formatted": "250.5m" },
+                "mem_req": { "bytes": 3072, "formatted": "3Ki" },
+            },
+            "summary": "2 pods",
+        });
+
+        let output = render(Format::Json, "pods", &options, &table(), Some("2 pods".to_string()), &[]).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(json, expected);
+
+        // YAML도 같은 구조 (filter, warnings처럼 값이 없는 항목은 생략)
+        let output = render(Format::Yaml, "pods", &options, &table(), Some("2 pods".to_string()), &[]).unwrap();
+        let yaml: serde_json::Value = serde_yaml::from_str(&output).unwrap();
+        assert_eq!(yaml, expected);
+        assert!(!output.contains("filter") && !output.contains("warnings"), "{}", output);
+    }
+}
@@ -0,0 +1,32 @@
+use tabled::builder::Builder;
+use tabled::settings::object::Columns;
+use tabled::settings::{Alignment, Padding, Style};
+
+use crate::models::table::{Align, Table};
+
+// kubectl처럼 테두리 없는 텍스트 테이블 (include_total이면 마지막 행은 TOTAL)
+pub fn render(table: &Table, include_total: bool) -> String {
+    let mut builder = Builder::default();
+    builder.push_record(table.headers.iter().map(|header| header.to_string()));
+    for row in &table.rows {
+        builder.push_record(row.iter().map(|value| value.to_string()));
+    }
+    if include_total {
+        builder.push_record(table.total.iter().map(|value| value.to_string()));
+    }
+
+    let mut rendered = builder.build();
+    rendered.with(Style::blank()).with(Padding::new(0, 3, 0, 0));
+    for (i, align) in table.aligns.iter().enumerate() {
+        if *align == Align::Right {
+            rendered.modify(Columns::single(i), Alignment::right());
+        }
+    }
+    // 줄 끝 공백 제거 (스크립트에서 다루기 쉽도록)
+    rendered
+        .to_string()
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,350 @@
+use chrono::{Local, Utc};
+use futures::stream::{self, StreamExt};
+use futures::FutureExt;
+use k8s_openapi::api::core::v1::Pod;
+use kube::runtime::watcher::{self, watcher, Event};
+use kube::runtime::WatchStreamExt;
+use kube::Api;
+use std::collections::{BTreeMap, HashSet};
+
+use crate::api::context::KubeContext;
+use crate::api::pod::{pod_row, pod_table, PodRow, POD_COLUMNS};
+use crate::models::cli::OutputFormat;
+use crate::models::config::{SearchConfig, SortConfig};
+use crate::models::error::AppError;
+use crate::models::table::{CellValue, Column, Table};
+use crate::models::warning::ParseWarning;
+use crate::output::{print_warnings, structured, text};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Change {
+    Added,
+    Modified,
+    Deleted,
+}
+
+impl Change {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Change::Added => "ADDED",
+            Change::Modified => "MODIFIED",
+            Change::Deleted => "DELETED",
+        }
+    }
+}
+
+// 표시되는 컬럼이 바뀐 행 (상태만 바뀌고 컬럼 값이 같으면 변경으로 보지 않음)
+struct RowChange {
+    change: Change,
+    old: Option<PodRow>,
+    new: Option<PodRow>,
+}
+
+fn zero(cell: &CellValue) -> CellValue {
+    match cell {
+        CellValue::Cpu(_) => CellValue::Cpu(Default::default()),
+        CellValue::Memory(_) => CellValue::Memory(Default::default()),
+        CellValue::Count(_) => CellValue::Count(0),
+        _ => CellValue::Text(String::new()),
+    }
+}
+
+fn difference(new: &CellValue, old: &CellValue) -> CellValue {
+    match (new, old) {
+        (CellValue::Cpu(a), CellValue::Cpu(b)) => CellValue::Cpu(*a - *b),
+        (CellValue::Memory(a), CellValue::Memory(b)) => CellValue::Memory(*a - *b),
+        (CellValue::Count(a), CellValue::Count(b)) => CellValue::Count(a.saturating_sub(*b)),
+        _ => CellValue::Text(String::new()),
+    }
+}
+
+// 합계 컬럼의 변화량 (추가된 행은 새 값, 삭제된 행은 음수)
+fn delta(summable: &[bool], new: Option<&[CellValue]>, old: Option<&[CellValue]>) -> Vec<CellValue> {
+    summable
+        .iter()
+        .enumerate()
+        .map(|(i, &summable)| {
+            let (new, old) = (new.map(|cells| &cells[i]), old.map(|cells| &cells[i]));
+            match (summable, new, old) {
+                (false, _, _) => CellValue::Text(String::new()),
+                (true, Some(new), Some(old)) => difference(new, old),
+                (true, Some(new), None) => difference(new, &zero(new)),
+                (true, None, Some(old)) => difference(&zero(old), old),
+                (true, None, None) => CellValue::Text(String::new()),
+            }
+        })
+        .collect()
+}
+
+fn column_keys(headers: &[&str]) -> Vec<String> {
+    headers.iter().map(|header| structured::column_key(header)).collect()
+}
+
+// watch 없이 -o ndjson: 현재 행을 모두 ADDED 이벤트로 출력
+pub fn print_snapshot(kind: &str, table: &Table) -> Result<(), AppError> {
+    let keys = column_keys(&table.headers);
+    // TOTAL 행에 값이 있는 컬럼이 합계 컬럼
+    let summable: Vec<bool> = table
+        .total
+        .iter()
+        .enumerate()
+        .map(|(i, cell)| i > 0 && !matches!(cell, CellValue::Text(_)))
+        .collect();
+    let time = Utc::now().to_rfc3339();
+    for row in &table.rows {
+        let delta = delta(&summable, Some(row), None);
+        println!("{}", structured::event_line(Change::Added.as_str(), &time, kind, &keys, row, &delta)?);
+    }
+    Ok(())
+}
+
+fn pod_cells(row: &PodRow) -> Vec<CellValue> {
+    POD_COLUMNS.iter().map(|column| column.value(row)).collect()
+}
+
+fn matches_filter(search_config: SearchConfig, row: &PodRow) -> bool {
+    match POD_COLUMNS.get(search_config.column) {
+        Some(column) => column.format(row) == search_config.get_word(),
+        None => true,
+    }
+}
+
+fn pod_key(row: &PodRow) -> (String, String) {
+    (row.namespace.clone(), row.name.clone())
+}
+
+fn apply(store: &mut BTreeMap<(String, String), PodRow>, row: PodRow, changes: &mut Vec<RowChange>) {
+    match store.insert(pod_key(&row), row.clone()) {
+        None => changes.push(RowChange { change: Change::Added, old: None, new: Some(row) }),
+        Some(old) if pod_cells(&old) != pod_cells(&row) => {
+            changes.push(RowChange { change: Change::Modified, old: Some(old), new: Some(row) })
+        }
+        Some(_) => {}
+    }
+}
+
+// 최초 목록 또는 watch가 만료되어 다시 받은 전체 목록으로 교체
+// namespace별 watcher는 자기 namespace의 행만 교체 (None은 클러스터 전체)
+fn restart(
+    store: &mut BTreeMap<(String, String), PodRow>,
+    namespace: Option<&str>,
+    rows: Vec<PodRow>,
+    changes: &mut Vec<RowChange>,
+) {
+    let listed: HashSet<(String, String)> = rows.iter().map(pod_key).collect();
+    for row in rows {
+        apply(store, row, changes);
+    }
+    store.retain(|key, old| {
+        let keep = listed.contains(key) || namespace.is_some_and(|namespace| key.0 != namespace);
+        if !keep {
+            changes.push(RowChange { change: Change::Deleted, old: Some(old.clone()), new: None });
+        }
+        keep
+    });
+}
+
+// 같은 경고는 한 번만 stderr로 출력 (Pod가 갱신될 때마다 다시 파싱되므로)
+fn print_new_warnings(printed: &mut HashSet<String>, warnings: Vec<ParseWarning>) {
+    let new: Vec<ParseWarning> = warnings
+        .into_iter()
+        .filter(|warning| {
+            printed.insert(format!(
+                "{}/{}/{}/{}/{}",
+                warning.namespace, warning.name, warning.container, warning.field, warning.value
+            ))
+        })
+        .collect();
+    print_warnings(&new);
+}
+
+// kubectl get -w처럼 Pod 변경을 계속 출력 (테이블 재출력 또는 ndjson 이벤트)
+pub async fn watch_pods(
+    ctx: &KubeContext,
+    format: OutputFormat,
+    search_config: SearchConfig,
+    sort_config: SortConfig,
+) -> Result<(), AppError> {
+    if !matches!(format, OutputFormat::Table | OutputFormat::Ndjson) {
+        return Err(AppError::ParseError("--watch supports only -o table and -o ndjson".to_string()));
+    }
+
+    // 이벤트마다 어느 watcher에서 왔는지 namespace를 붙임 (클러스터 전체는 None)
+    let apis: Vec<(Option<String>, Api<Pod>)> = if ctx.scope.is_cluster_wide() {
+        vec![(None, Api::all(ctx.client.clone()))]
+    } else {
+        ctx.scope
+            .namespaces
+            .iter()
+            .map(|namespace| (Some(namespace.clone()), Api::namespaced(ctx.client.clone(), namespace)))
+            .collect()
+    };
+    let config = watcher::Config::default().page_size(ctx.request.page_size);
+    let mut events = stream::select_all(apis.into_iter().map(|(namespace, api)| {
+        watcher(api, config.clone())
+            .default_backoff()
+            .map(move |event| (namespace.clone(), event))
+            .boxed()
+    }));
+
+    let keys = column_keys(&POD_COLUMNS.iter().map(|column| column.header).collect::<Vec<_>>());
+    let summable: Vec<bool> = POD_COLUMNS.iter().map(|column| column.summable).collect();
+    let mut store: BTreeMap<(String, String), PodRow> = BTreeMap::new();
+    let mut printed_warnings = HashSet::new();
+
+    while let Some(event) = events.next().await {
+        // 한꺼번에 도착한 이벤트는 묶어서 한 번만 출력
+        let mut batch = vec![event];
+        while let Some(Some(event)) = events.next().now_or_never() {
+            batch.push(event);
+        }
+
+        let mut changes = Vec::new();
+        let mut warnings = Vec::new();
+        for (namespace, event) in batch {
+            match event {
+                Ok(Event::Applied(pod)) => apply(&mut store, pod_row(pod, &mut warnings), &mut changes),
+                Ok(Event::Deleted(pod)) => {
+                    let row = pod_row(pod, &mut Vec::new());
+                    if let Some(old) = store.remove(&pod_key(&row)) {
+                        changes.push(RowChange { change: Change::Deleted, old: Some(old), new: None });
+                    }
+                }
+                Ok(Event::Restarted(pods)) => {
+                    let rows = pods.into_iter().map(|pod| pod_row(pod, &mut warnings)).collect();
+                    restart(&mut store, namespace.as_deref(), rows, &mut changes);
+                }
+                Err(e) => eprintln!("watch error: {}", e),
+            }
+        }
+        print_new_warnings(&mut printed_warnings, warnings);
+
+        // 필터에 걸리는 행의 변경만 출력
+        changes.retain(|change| {
+            change.old.iter().chain(&change.new).any(|row| matches_filter(search_config, row))
+        });
+        if changes.is_empty() {
+            continue;
+        }
+
+        if format == OutputFormat::Ndjson {
+            let time = Utc::now().to_rfc3339();
+            for change in &changes {
+                let (old, new) = (change.old.as_ref().map(pod_cells), change.new.as_ref().map(pod_cells));
+                let delta = delta(&summable, new.as_deref(), old.as_deref());
+                let Some(cells) = new.as_ref().or(old.as_ref()) else { continue };
+                println!("{}", structured::event_line(change.change.as_str(), &time, "pods", &keys, cells, &delta)?);
+            }
+        } else {
+            let count = |kind: Change| changes.iter().filter(|change| change.change == kind).count();
+            println!(
+                "\n{}  {} added, {} modified, {} deleted",
+                Local::now().format("%H:%M:%S"),
+                count(Change::Added),
+                count(Change::Modified),
+                count(Change::Deleted),
+            );
+            let rows: Vec<PodRow> = store.values().cloned().collect();
+            println!("{}", text::render(&pod_table(&rows, Some(search_config), Some(sort_config)), true));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::resource::{Cpu, Memory, Resources};
+
+    fn row(namespace: &str, name: &str, cpu_millis: i64) -> PodRow {
+        PodRow {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+            status: "Running".to_string(),
+            node: "node-1".to_string(),
+            resources: Resources {
+                cpu_request: Cpu::from_nanocores(cpu_millis * 1_000_000),
+                memory_request: Memory::from_bytes(64 * 1024 * 1024),
+                ..Default::default()
+            },
+            priority_class: String::new(),
+            priority: None,
+            workload: format!("Pod/{}", name),
+        }
+    }
+
+    fn kinds(changes: &[RowChange]) -> Vec<(Change, String)> {
+        changes
+            .iter()
+            .map(|change| {
+                let row = change.new.as_ref().or(change.old.as_ref()).unwrap();
+                (change.change, format!("{}/{}", row.namespace, row.name))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_apply() {
+        let mut store = BTreeMap::new();
+        let mut changes = Vec::new();
+        apply(&mut store, row("a", "web", 100), &mut changes);
+        // 표시되는 컬럼이 같으면 변경 아님
+        apply(&mut store, row("a", "web", 100), &mut changes);
+        apply(&mut store, row("a", "web", 250), &mut changes);
+
+        assert_eq!(
+            kinds(&changes),
+            vec![(Change::Added, "a/web".to_string()), (Change::Modified, "a/web".to_string())]
+        );
+        assert_eq!(store.len(), 1);
+    }
+
+    #[test]
+    fn test_restart_keeps_other_namespaces() {
+        let mut store = BTreeMap::new();
+        let mut changes = Vec::new();
+        restart(&mut store, Some("a"), vec![row("a", "web", 100), row("a", "db", 200)], &mut changes);
+        restart(&mut store, Some("b"), vec![row("b", "api", 100)], &mut changes);
+        assert_eq!(store.len(), 3);
+        assert_eq!(changes.len(), 3);
+
+        // b를 다시 받아도 a의 Pod는 삭제되지 않음
+        changes.clear();
+        restart(&mut store, Some("b"), vec![row("b", "worker", 100)], &mut changes);
+        assert_eq!(
+            kinds(&changes),
+            vec![(Change::Added, "b/worker".to_string()), (Change::Deleted, "b/api".to_string())]
+        );
+        assert_eq!(store.len(), 3);
+
+        // 클러스터 전체 목록은 빠진 Pod를 모두 삭제
+        changes.clear();
+        restart(&mut store, None, vec![row("a", "web", 100)], &mut changes);
+        assert_eq!(
+            kinds(&changes),
+            vec![(Change::Deleted, "a/db".to_string()), (Change::Deleted, "b/worker".to_string())]
+        );
+        assert_eq!(store.len(), 1);
+    }
+
+    #[test]
+    fn test_delta() {
+        let summable = [false, true, true];
+        let old = [CellValue::Text("web".to_string()), CellValue::Cpu(Cpu::from_nanocores(300)), CellValue::Count(2)];
+        let new = [CellValue::Text("web".to_string()), CellValue::Cpu(Cpu::from_nanocores(100)), CellValue::Count(5)];
+        let empty = CellValue::Text(String::new());
+
+        assert_eq!(
+            delta(&summable, Some(&new), Some(&old)),
+            vec![empty.clone(), CellValue::Cpu(Cpu::from_nanocores(-200)), CellValue::Count(3)]
+        );
+        assert_eq!(
+            delta(&summable, Some(&new), None),
+            vec![empty.clone(), CellValue::Cpu(Cpu::from_nanocores(100)), CellValue::Count(5)]
+        );
+        assert_eq!(
+            delta(&summable, None, Some(&old)),
+            vec![empty, CellValue::Cpu(Cpu::from_nanocores(-300)), CellValue::Count(-2)]
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use crate::models::config::SearchConfig;
+use crate::output::export::{export_view, ExportFormat};
 use crate::ViewMode;
 use crate::AppState;
 
@@ -14,83 +15,146 @@ pub fn handle_event(
             KeyCode::Char('q') | KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                 return false; // 프로그램 종료
             }
+            // 내보내기 형식 선택 (다른 키는 취소)
+            KeyCode::Char(format @ ('c' | 'j' | 'm')) if app_state.export_prompt => {
+                app_state.export_prompt = false;
+                let format = match format {
+                    'c' => ExportFormat::Csv,
+                    'j' => ExportFormat::Json,
+                    _ => ExportFormat::Markdown,
+                };
+                app_state.last_export = Some(export_view(app_state, format));
+            }
+            _ if app_state.export_prompt => {
+                app_state.export_prompt = false;
+            }
+            // '/' 검색어 입력 중: 입력할 때마다 바로 필터
+            KeyCode::Esc if app_state.text_search.editing => {
+                app_state.text_search.clear();
+                app_state.update_search();
+            }
+            KeyCode::Enter if app_state.text_search.editing => {
+                app_state.text_search.editing = false;
+            }
+            KeyCode::Tab if app_state.text_search.editing => {
+                app_state.text_search.cycle_mode();
+                app_state.update_search();
+            }
+            KeyCode::Backspace if app_state.text_search.editing => {
+                app_state.text_search.pop();
+                app_state.update_search();
+            }
+            KeyCode::Char(c) if app_state.text_search.editing && !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                app_state.text_search.push(c);
+                app_state.update_search();
+            }
+            KeyCode::Char('/') => {
+                app_state.text_search.editing = true;
+            }
+            KeyCode::Char('e') if !app_state.table.headers.is_empty() => {
+                app_state.export_prompt = true;
+                app_state.last_export = None;
+            }
             KeyCode::Char(' ') => {
                 app_state.is_loading = true;
             }
-            KeyCode::Esc => {
+            // replay: 이전/다음 snapshot ([ ]는 1개, { }는 10개씩)
+            KeyCode::Char(key @ ('[' | ']' | '{' | '}')) if app_state.replay.is_some() => {
+                let offset = match key {
+                    '[' => -1,
+                    ']' => 1,
+                    '{' => -10,
+                    _ => 10,
+                };
+                if let Some(replay) = app_state.replay.as_mut() {
+                    replay.seek(offset);
+                }
                 app_state.is_loading = true;
+            }
+            KeyCode::Char('l') if app_state.last_error.is_some() => {
+                app_state.show_error = !app_state.show_error;
+            }
+            KeyCode::Esc if app_state.show_error => {
+                app_state.show_error = false;
+            }
+            KeyCode::Char('w') if app_state.show_warnings || !app_state.warnings.is_empty() => {
+                app_state.toggle_warnings();
+            }
+            KeyCode::Esc if app_state.show_warnings => {
+                app_state.toggle_warnings();
+            }
+            KeyCode::Esc => {
                 app_state.search_config = SearchConfig::new(999, "");
+                app_state.text_search.clear();
+                app_state.selected_row = 0;
+                app_state.scroll_offset = 0;
+                app_state.rebuild_rows();
             }
             KeyCode::Tab => {
+                app_state.show_warnings = false;
                 app_state.toggle_view_mode();
                 app_state.is_loading = true;
                 app_state.selected_row = 0;
                 app_state.scroll_offset = 0;
                 app_state.sort_config.column = 0;
                 app_state.search_config = SearchConfig::new(999, "");
+                app_state.text_search.clear();
 
             }
-            KeyCode::Enter => {
-                if let Some(row) = app_state.rows.get(app_state.selected_row + 1) {
-                    let column_index = match app_state.view_mode {
-                        ViewMode::Node => 3,
-                        ViewMode::Namespace => 0,
+            KeyCode::Enter if !app_state.show_warnings => {
+                if let Some(row) = app_state.table.rows.get(app_state.selected_row) {
+                    let (target_view, column_index) = match app_state.view_mode {
+                        ViewMode::Node => (ViewMode::Pod, 3),
+                        ViewMode::Namespace => (ViewMode::Pod, 0),
+                        ViewMode::Priority => (ViewMode::Pod, 3),
+                        ViewMode::Storage => (ViewMode::Pvc, 0),
+                        ViewMode::StorageClass => (ViewMode::Pvc, 3),
                         _ => return true
                     };
                     app_state.is_loading = true;
                     app_state.selected_row = 0;
                     app_state.scroll_offset = 0;
-                    app_state.view_mode = ViewMode::Pod;
-                    app_state.search_config.set_word(row[0].as_str());
+                    app_state.view_mode = target_view;
+                    app_state.search_config.set_word(&row[0].to_string());
                     app_state.search_config.column = column_index;
+                    app_state.text_search.clear();
                 }
             }
             KeyCode::Left => {
                 if app_state.sort_config.column > 0 {
                     app_state.sort_config.column -= 1;
                 }
-                app_state.is_loading = true;
+                app_state.rebuild_rows();
             }
             KeyCode::Right => {
-                let max_columns = match app_state.view_mode {
-                    ViewMode::Node => 6,       // Node는 7개 컬럼
-                    ViewMode::Pod => 7,        // Pod는 6개 컬럼
-                    ViewMode::Namespace => 4,  // Namespace는 5개 컬럼
-                };
+                let max_columns = app_state.table.headers.len().saturating_sub(1);
                 if app_state.sort_config.column < max_columns {
                     app_state.sort_config.column += 1;
                 }
-                app_state.is_loading = true;
+                app_state.rebuild_rows();
             }
-            KeyCode::Up => {
-                if app_state.selected_row > 0 {
-                    app_state.selected_row -= 1;
-                    if app_state.selected_row < app_state.scroll_offset {
-                        app_state.scroll_offset -= 1; // 스크롤 업
-                    }
+            KeyCode::Up if app_state.selected_row > 0 => {
+                app_state.selected_row -= 1;
+                if app_state.selected_row < app_state.scroll_offset {
+                    app_state.scroll_offset -= 1; // 스크롤 업
                 }
             }
-            KeyCode::Down => {
-                if app_state.selected_row < app_state.rows.len() - 3 {
-                    app_state.selected_row += 1;
-                    if app_state.selected_row >= app_state.scroll_offset + app_state.visible_height {
-                        app_state.scroll_offset += 1; // 스크롤 다운
-                    }
+            KeyCode::Down if app_state.selected_row + 1 < app_state.table.rows.len() => {
+                app_state.selected_row += 1;
+                if app_state.selected_row >= app_state.scroll_offset + app_state.visible_height {
+                    app_state.scroll_offset += 1; // 스크롤 다운
                 }
             }
-            KeyCode::PageUp => {
-                if app_state.scroll_offset > 0 {
-                    let page_size = app_state.visible_height;
-                    app_state.scroll_offset = app_state.scroll_offset.saturating_sub(page_size);
-                    app_state.selected_row = app_state.selected_row.saturating_sub(page_size);
-                }
+            KeyCode::PageUp if app_state.scroll_offset > 0 => {
+                let page_size = app_state.visible_height;
+                app_state.scroll_offset = app_state.scroll_offset.saturating_sub(page_size);
+                app_state.selected_row = app_state.selected_row.saturating_sub(page_size);
             }
-            KeyCode::PageDown => {
-                if app_state.scroll_offset + app_state.visible_height < app_state.rows.len() -3 {
-                    let page_size = app_state.visible_height;
-                    app_state.scroll_offset = (app_state.scroll_offset + page_size).min(app_state.rows.len().saturating_sub(1));
-                    app_state.selected_row = (app_state.selected_row + page_size).min(app_state.rows.len().saturating_sub(1));
-                }
+            KeyCode::PageDown if app_state.scroll_offset + app_state.visible_height < app_state.table.rows.len().saturating_sub(1) => {
+                let page_size = app_state.visible_height;
+                let last_row = app_state.table.rows.len().saturating_sub(1);
+                app_state.scroll_offset = (app_state.scroll_offset + page_size).min(last_row);
+                app_state.selected_row = (app_state.selected_row + page_size).min(last_row);
             }
             _ => {}
         }
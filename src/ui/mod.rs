@@ -1,2 +1,3 @@
+#[allow(clippy::module_inception)]
 pub mod ui;
 pub mod event;
\ No newline at end of file
@@ -1,7 +1,52 @@
+use chrono::Local;
 use tui::{
-    backend::Backend, layout::{Alignment, Constraint, Direction, Layout}, style::{Color, Modifier, Style}, text::Spans, widgets::{Block, Borders, Cell, Paragraph, Row, Table}, Frame
+    backend::Backend, layout::{Alignment, Constraint, Direction, Layout, Rect}, style::{Color, Modifier, Style}, text::{Span, Spans}, widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, Wrap}, Frame
 };
-use crate::{models::app::ViewMode, AppState};
+use crate::{models::app::{ConnectionHealth, ViewMode}, AppState};
+use crate::models::table::{Align, CellValue};
+use crate::util::common::format_count;
+
+fn view_title(view_mode: ViewMode) -> &'static str {
+    match view_mode {
+        ViewMode::Namespace => "Namespace",
+        ViewMode::Node => "Node",
+        ViewMode::Pod => "Pod",
+        ViewMode::Priority => "Priority Bands by Node",
+        ViewMode::Hpa => "HPA",
+        ViewMode::Storage => "Storage by Namespace",
+        ViewMode::StorageClass => "Storage by Storage Class",
+        ViewMode::Pvc => "PVC",
+    }
+}
+
+fn filtered_title(view_name: &str, app_state: &AppState) -> String {
+    match app_state.search_config.column {
+        999 => view_name.to_string(),
+        _ => {
+            let column = app_state.table.headers.get(app_state.search_config.column).copied().unwrap_or_default();
+            let value = app_state.search_config.get_word();
+            format!("{} - Filtered -> {}: {}", view_name, column, value)
+        }
+    }
+}
+
+// 검색어와 일치한 부분을 강조한 텍스트
+fn highlighted(text: &str, ranges: &[std::ops::Range<usize>]) -> Spans<'static> {
+    let highlight = Style::default().fg(Color::Black).bg(Color::Cyan).add_modifier(Modifier::BOLD);
+    let mut spans = Vec::new();
+    let mut position = 0;
+    for range in ranges {
+        if range.start > position {
+            spans.push(Span::raw(text[position..range.start].to_string()));
+        }
+        spans.push(Span::styled(text[range.clone()].to_string(), highlight));
+        position = range.end;
+    }
+    if position < text.len() {
+        spans.push(Span::raw(text[position..].to_string()));
+    }
+    Spans::from(spans)
+}
 
 pub fn draw_ui<B: Backend>(f: &mut Frame<B>, app_state: &mut AppState) {
     let chunks = Layout::default()
@@ -9,6 +54,7 @@ pub fn draw_ui<B: Backend>(f: &mut Frame<B>, app_state: &mut AppState) {
         .constraints([
             Constraint::Length(10),
             Constraint::Min(0),
+            Constraint::Length(1),
         ])
         .split(f.size());
 
@@ -30,17 +76,45 @@ pub fn draw_ui<B: Backend>(f: &mut Frame<B>, app_state: &mut AppState) {
 
     app_state.visible_height = (table_chunks[0].height as usize).saturating_sub(2);
 
-    let table_widths = app_state.get_widths();
+    let column_widths = app_state.get_widths();
+    let table_widths: Vec<Constraint> = column_widths.iter().map(|&w| Constraint::Length(w)).collect();
+    let aligned_cell = |i: usize, text: String| {
+        let column = i + app_state.scroll_offset_horizontal;
+        match (app_state.table.aligns.get(column), column_widths.get(column)) {
+            (Some(Align::Right), Some(&width)) => Cell::from(format!("{:>1$}", text, width as usize)),
+            _ => Cell::from(text),
+        }
+    };
+
+    let hidden_columns = if app_state.show_warnings {
+        Vec::new()
+    } else {
+        app_state.permissions.hidden_columns(app_state.view_mode)
+    };
+    let unavailable_reason = if app_state.show_warnings { None } else { app_state.unavailable_reason() };
 
-    if !app_state.rows.is_empty() {
+    if let Some(reason) = unavailable_reason.clone() {
+        let message = Paragraph::new(vec![Spans::from(""), Spans::from(reason)])
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::DarkGray))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(view_title(app_state.view_mode))
+                    .style(Style::default().fg(Color::DarkGray)));
+        f.render_widget(message, table_chunks[0]);
+    } else if !app_state.table.headers.is_empty() {
         let header_style = Style::default().fg(Color::White).add_modifier(Modifier::BOLD);
         let header = Row::new(
-            app_state.rows[0]
+            app_state.table.headers
                 .iter()
                 .skip(app_state.scroll_offset_horizontal) // 가로 스크롤 적용
                 .enumerate()
                 .map(|(i, h)| {
-                    let mut cell = Cell::from(h.as_str()).style(header_style);
+                    let mut cell = aligned_cell(i, h.to_string()).style(header_style);
+                    if hidden_columns.contains(&(i + app_state.scroll_offset_horizontal)) {
+                        cell = cell.style(Style::default().fg(Color::DarkGray));
+                    }
                     if app_state.sort_config.column == i + app_state.scroll_offset_horizontal {
                         cell = cell.style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
                     }
@@ -52,10 +126,9 @@ pub fn draw_ui<B: Backend>(f: &mut Frame<B>, app_state: &mut AppState) {
 
         let mut visible_rows = vec![header];
         visible_rows.extend(
-            app_state.rows
+            app_state.table.rows
                 .iter()
-                .take(app_state.rows.len() - 1)
-                .skip(1 + app_state.scroll_offset)
+                .skip(app_state.scroll_offset)
                 .take(app_state.visible_height)
                 .enumerate()
                 .map(|(i, row)| {
@@ -64,27 +137,42 @@ pub fn draw_ui<B: Backend>(f: &mut Frame<B>, app_state: &mut AppState) {
                     } else {
                         Style::default().fg(Color::Gray)
                     };
-                    let cells: Vec<tui::widgets::Cell> = row
+                    let cells: Vec<Cell> = row
                         .iter()
                         .skip(app_state.scroll_offset_horizontal) // 가로 스크롤 적용
-                        .map(|s| tui::widgets::Cell::from(s.as_str()))
+                        .enumerate()
+                        .map(|(i, value)| match value {
+                            // 왼쪽 정렬 텍스트 컬럼만 강조 (오른쪽 정렬은 공백을 채워 위치가 달라짐)
+                            CellValue::Text(text) if app_state.table.aligns.get(i + app_state.scroll_offset_horizontal) == Some(&Align::Left) => {
+                                match app_state.text_search.find(text) {
+                                    Some(ranges) if !ranges.is_empty() => Cell::from(highlighted(text, &ranges)),
+                                    _ => aligned_cell(i, text.clone()),
+                                }
+                            }
+                            _ => aligned_cell(i, value.to_string()),
+                        })
                         .collect();
                     Row::new(cells).style(style)
                 })
         );
 
-        let title = match app_state.view_mode {
-            ViewMode::Namespace => "Namespace".to_string(),
-            ViewMode::Node => "Node".to_string(),
-            ViewMode::Pod => match app_state.search_config.column {
-                999 => "Pod".to_string(),
-                _ => {
-                    let column = app_state.rows[0][app_state.search_config.column].clone();
-                    let value = app_state.search_config.get_word();
-                    format!("Pod - Filtered -> {}: {}", column, value)
-                }
-            }
+        let mut title = match app_state.view_mode {
+            _ if app_state.show_warnings => format!(
+                "Parse Warnings from {} view (w/Esc: back)",
+                view_title(app_state.view_mode),
+            ),
+            ViewMode::Pod | ViewMode::Pvc => filtered_title(view_title(app_state.view_mode), app_state),
+            _ => view_title(app_state.view_mode).to_string(),
         };
+        if let Some(search) = app_state.text_search.describe() {
+            title = format!("{} - Search {}: {} matches", title, search, format_count(app_state.table.rows.len()));
+        }
+        if let Some(summary) = &app_state.summary {
+            title = format!("{} - {}", title, summary);
+        }
+        if !app_state.scope_config.is_cluster_wide() {
+            title = format!("{} [namespaces: {}]", title, app_state.scope_config.namespaces.join(", "));
+        }
 
         let table = Table::new(visible_rows)
             .block(
@@ -96,12 +184,13 @@ pub fn draw_ui<B: Backend>(f: &mut Frame<B>, app_state: &mut AppState) {
         f.render_widget(table, table_chunks[0]);
     }
 
-    if app_state.rows.len() > 1 {
+    if !app_state.table.total.is_empty() && unavailable_reason.is_none() && !app_state.show_warnings {
         let total_style = Style::default().fg(Color::Green).add_modifier(Modifier::BOLD);
-        let total_cells: Vec<tui::widgets::Cell> = app_state.rows.last().unwrap()
+        let total_cells: Vec<Cell> = app_state.table.total
             .iter()
             .skip(app_state.scroll_offset_horizontal) // 가로 스크롤 적용
-            .map(|s| tui::widgets::Cell::from(s.as_str()))
+            .enumerate()
+            .map(|(i, value)| aligned_cell(i, value.to_string()))
             .collect();
         let total_row = Row::new(total_cells).style(total_style);
 
@@ -123,12 +212,12 @@ pub fn draw_ui<B: Backend>(f: &mut Frame<B>, app_state: &mut AppState) {
         Spans::from("██║░╚██╗╚██████╔╝██████╦╝███████╗░░░░░░██║░░██║██║░╚═╝░██║"),
         Spans::from("╚═╝░░╚═╝░╚═════╝░╚═════╝░╚══════╝░░░░░░╚═╝░░╚═╝╚═╝░░░░░╚═╝"),
         Spans::from(""),
-        Spans::from("↑/↓: Scroll | ←/→: Sort | Tab: Move | Space Bar : refresh | q: Quit"),
+        Spans::from("↑/↓: Scroll | ←/→: Sort | Tab: Move | Space Bar : refresh | l: Last error | w: Warnings | e: Export | /: Search | q: Quit"),
     ];
 
     let version = env!("CARGO_PKG_VERSION");
     let team_text = vec![
-        Spans::from(""),
+        health_spans(app_state),
         Spans::from(""),
         Spans::from(""),
         Spans::from(""),
@@ -147,4 +236,194 @@ pub fn draw_ui<B: Backend>(f: &mut Frame<B>, app_state: &mut AppState) {
         .alignment(Alignment::Right),
         banner_chunks[1],
     );
-}
\ No newline at end of file
+
+    draw_status_bar(f, app_state, chunks[2]);
+
+    if app_state.show_error {
+        draw_error_popup(f, app_state);
+    }
+}
+
+fn health_spans(app_state: &AppState) -> Spans<'static> {
+    if app_state.replay.is_some() {
+        return Spans::from(vec![
+            Span::raw("API: "),
+            Span::styled("● Replay (offline)", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Span::raw("  "),
+        ]);
+    }
+    let (label, color) = match app_state.health {
+        ConnectionHealth::Unknown => ("Connecting", Color::Gray),
+        ConnectionHealth::Connected => ("Connected", Color::Green),
+        ConnectionHealth::Degraded => ("Degraded", Color::Yellow),
+        ConnectionHealth::Disconnected => ("Disconnected", Color::Red),
+    };
+    let latency = app_state
+        .api_latency
+        .map(|latency| format!(" ({}ms)", latency.as_millis()))
+        .unwrap_or_default();
+
+    Spans::from(vec![
+        Span::raw("API: "),
+        Span::styled(format!("● {}", label), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+        Span::raw(format!("{}  ", latency)),
+    ])
+}
+
+fn format_age(seconds: i64) -> String {
+    if seconds < 60 {
+        format!("{}s", seconds)
+    } else if seconds < 3600 {
+        format!("{}m {}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{}h {}m", seconds / 3600, (seconds % 3600) / 60)
+    }
+}
+
+// 전체 snapshot 중 현재 위치 (◀━━●━━━▶)
+fn scrubber(position: usize, count: usize, width: usize) -> String {
+    let marker = if count > 1 { position * (width - 1) / (count - 1) } else { 0 };
+    let track: String = (0..width).map(|i| if i == marker { '●' } else { '━' }).collect();
+    format!("◀{}▶", track)
+}
+
+fn draw_status_bar<B: Backend>(f: &mut Frame<B>, app_state: &AppState, area: Rect) {
+    let mut spans = Vec::new();
+
+    if app_state.is_fetching {
+        let progress = app_state.progress.snapshot();
+        let label = if progress.kind.is_empty() {
+            " ● Loading... ".to_string()
+        } else {
+            match progress.estimated {
+                Some(estimated) => format!(
+                    " ● Loading {}... {} / ~{} ",
+                    progress.kind,
+                    format_count(progress.loaded),
+                    format_count(estimated),
+                ),
+                None => format!(" ● Loading {}... {} ", progress.kind, format_count(progress.loaded)),
+            }
+        };
+        spans.push(Span::styled(label, Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)));
+    } else {
+        spans.push(Span::styled(" ● Idle ", Style::default().fg(Color::Green)));
+    }
+
+    match (&app_state.replay, app_state.last_refresh) {
+        (Some(replay), _) => {
+            let count = replay.snapshots.len();
+            spans.push(Span::styled(
+                format!(
+                    "| Replay {} [{}/{}] {} ",
+                    replay.current().time.format("%Y-%m-%d %H:%M:%S"),
+                    replay.position + 1,
+                    count,
+                    scrubber(replay.position, count, 20),
+                ),
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            ));
+            spans.push(Span::raw("([/]: ±1, {/}: ±10) "));
+        }
+        (None, Some(last_refresh)) => {
+            let age = (Local::now() - last_refresh).num_seconds().max(0);
+            spans.push(Span::raw(format!(
+                "| Last refresh: {} (age {}) ",
+                last_refresh.format("%H:%M:%S"),
+                format_age(age),
+            )));
+        }
+        (None, None) => spans.push(Span::raw("| Last refresh: never ")),
+    }
+
+    if let Some(recording) = &app_state.recording {
+        let recording = recording.lock().unwrap();
+        match &recording.last_error {
+            Some(e) => spans.push(Span::styled(
+                format!("| REC failed: {} ", e.to_string().lines().next().unwrap_or_default()),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )),
+            None => spans.push(Span::styled(
+                format!("| ● REC {} ({} snapshots) ", recording.path.display(), format_count(recording.count)),
+                Style::default().fg(Color::Red),
+            )),
+        }
+    }
+
+    if !app_state.warnings.is_empty() {
+        spans.push(Span::styled(
+            format!("| ⚠ {} unparseable quantities (w: details) ", format_count(app_state.warnings.len())),
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    let search = &app_state.text_search;
+    if search.editing {
+        spans.push(Span::styled(
+            format!("| /{}▏ [{}] ", search.query, search.mode.name()),
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        ));
+        match &search.error {
+            Some(error) => spans.push(Span::styled(
+                format!("invalid regex: {} ", error),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )),
+            None => spans.push(Span::raw("(Tab: mode | Enter: keep | Esc: clear) ")),
+        }
+    }
+
+    if app_state.export_prompt {
+        spans.push(Span::styled(
+            "| Export as: c: CSV | j: JSON | m: Markdown (other key: cancel) ",
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        ));
+    }
+    match &app_state.last_export {
+        Some(Ok(path)) => spans.push(Span::styled(
+            format!("| Exported to {} ", path.display()),
+            Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+        )),
+        Some(Err(e)) => spans.push(Span::styled(
+            format!("| Export failed: {} ", e),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )),
+        None => {}
+    }
+
+    if let Some(error) = &app_state.last_error {
+        // 첫 줄만 표시, 전체 내용은 'l' 키로 확인
+        let message = error.to_string().lines().next().unwrap_or_default().to_string();
+        spans.push(Span::styled(
+            format!("| {}: {} (l: details)", error.variant_name(), message),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    f.render_widget(Paragraph::new(Spans::from(spans)), area);
+}
+
+fn draw_error_popup<B: Backend>(f: &mut Frame<B>, app_state: &AppState) {
+    let Some(error) = &app_state.last_error else { return };
+
+    let size = f.size();
+    let width = size.width.saturating_mul(4) / 5;
+    let height = size.height / 2;
+    let area = Rect::new(
+        size.x + (size.width - width) / 2,
+        size.y + (size.height - height) / 2,
+        width,
+        height,
+    );
+
+    let popup = Paragraph::new(error.to_string())
+        .wrap(Wrap { trim: false })
+        .style(Style::default().fg(Color::Red))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("{} (l/Esc: close)", error.variant_name()))
+                .style(Style::default().fg(Color::Red)));
+
+    f.render_widget(Clear, area);
+    f.render_widget(popup, area);
+}
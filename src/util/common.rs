@@ -1,70 +1,168 @@
 use std::collections::BTreeMap;
+use std::ops::Range;
 use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
-use crate::{models::resource::ResourceValue, AppError};
+use crate::{models::resource::{Cpu, Memory}, AppError};
+use crate::models::warning::{ParseWarning, WarningSource};
 
-pub fn parse_cpu(quantity: &str) -> Result<ResourceValue, AppError> {
-    if quantity.is_empty() {
-        return Ok(ResourceValue(0));
+// Kubernetes Quantity 파싱 결과: mantissa * 10^exp10 * 2^exp2
+struct ParsedQuantity {
+    mantissa: i128,
+    exp10: i32,
+    exp2: u32,
+}
+
+// <quantity> ::= <signedNumber><suffix>
+// <suffix>   ::= <binarySI> | <decimalExponent> | <decimalSI>
+fn parse_quantity(quantity: &str) -> Result<ParsedQuantity, String> {
+    let bytes = quantity.as_bytes();
+    let mut pos = 0;
+
+    // 부호
+    let negative = match bytes.first() {
+        Some(b'-') => { pos += 1; true }
+        Some(b'+') => { pos += 1; false }
+        _ => false,
+    };
+
+    // 정수부와 소수부 (예: "1", "1.5", ".5", "5.")
+    let mut mantissa: i128 = 0;
+    let mut exp10: i32 = 0;
+    let mut digits = 0;
+    let mut seen_point = false;
+    while let Some(&c) = bytes.get(pos) {
+        match c {
+            b'0'..=b'9' => {
+                mantissa = mantissa
+                    .checked_mul(10)
+                    .and_then(|m| m.checked_add((c - b'0') as i128))
+                    .ok_or("number is too large")?;
+                if seen_point {
+                    exp10 -= 1;
+                }
+                digits += 1;
+            }
+            b'.' if !seen_point => seen_point = true,
+            _ => break,
+        }
+        pos += 1;
+    }
+    if digits == 0 {
+        return Err("missing number".to_string());
+    }
+    if negative {
+        mantissa = -mantissa;
     }
 
-    if quantity.ends_with('m') {
-        quantity
-            .trim_end_matches('m')
-            .parse::<i64>()
-            .map(ResourceValue)
-            .map_err(|e| AppError::ParseError(format!("Failed to parse CPU millicores: {}", e)))
-    } else {
-        quantity
-            .parse::<i64>()
-            .map(|v| ResourceValue(v * 1000))
-            .map_err(|e| AppError::ParseError(format!("Failed to parse CPU cores: {}", e)))
+    // 접미사
+    let suffix = &quantity[pos..];
+    let mut exp2 = 0;
+    match suffix {
+        "" => {}
+        "Ki" => exp2 = 10,
+        "Mi" => exp2 = 20,
+        "Gi" => exp2 = 30,
+        "Ti" => exp2 = 40,
+        "Pi" => exp2 = 50,
+        "Ei" => exp2 = 60,
+        "n" => exp10 -= 9,
+        "u" => exp10 -= 6,
+        "m" => exp10 -= 3,
+        "k" => exp10 += 3,
+        "M" => exp10 += 6,
+        "G" => exp10 += 9,
+        "T" => exp10 += 12,
+        "P" => exp10 += 15,
+        "E" => exp10 += 18,
+        _ if suffix.starts_with(['e', 'E']) => {
+            let exponent = suffix[1..]
+                .parse::<i32>()
+                .map_err(|_| format!("invalid exponent '{}'", suffix))?;
+            exp10 = exp10.checked_add(exponent).ok_or("exponent is too large")?;
+        }
+        _ => return Err(format!("unknown suffix '{}'", suffix)),
     }
+
+    Ok(ParsedQuantity { mantissa, exp10, exp2 })
+}
+
+// quantity를 10^scale 단위의 정수로 변환 (Kubernetes와 같이 올림)
+fn quantity_to_scaled(quantity: &str, scale: i32) -> Result<i64, String> {
+    let parsed = parse_quantity(quantity)?;
+    let exp10 = parsed.exp10.saturating_add(scale);
+    let overflow = || "value is out of range".to_string();
+
+    let numerator = parsed.mantissa
+        .checked_mul(2i128.checked_pow(parsed.exp2).ok_or_else(overflow)?)
+        .ok_or_else(overflow)?;
+    let value = if exp10 >= 0 {
+        numerator
+            .checked_mul(10i128.checked_pow(exp10 as u32).ok_or_else(overflow)?)
+            .ok_or_else(overflow)?
+    } else {
+        // 10^38을 넘는 나눗수는 결과가 0 또는 1 (올림)
+        match 10i128.checked_pow(exp10.unsigned_abs()) {
+            Some(denominator) => -(-numerator).div_euclid(denominator),
+            None => i128::from(numerator > 0),
+        }
+    };
+
+    i64::try_from(value).map_err(|_| overflow())
 }
 
-pub fn parse_memory(quantity: &str) -> Result<ResourceValue, AppError> {
+pub fn parse_cpu(quantity: &str) -> Result<Cpu, AppError> {
     if quantity.is_empty() {
-        return Ok(ResourceValue(0));
+        return Ok(Cpu::default());
     }
 
-    let parse_value = |s: &str| {
-        s.parse::<i64>()
-            .map_err(|e| AppError::ParseError(format!("Failed to parse memory value: {}", e)))
-    };
+    quantity_to_scaled(quantity, 9)
+        .map(Cpu::from_nanocores)
+        .map_err(|e| AppError::ParseError(format!("Failed to parse CPU quantity '{}': {}", quantity, e)))
+}
 
-    let result = if quantity.ends_with("Mi") {
-        parse_value(quantity.trim_end_matches("Mi")).map(|v| v * 1024 * 1024)
-    } else if quantity.ends_with("Gi") {
-        parse_value(quantity.trim_end_matches("Gi")).map(|v| v * 1024 * 1024 * 1024)
-    } else if quantity.ends_with("Ki") {
-        parse_value(quantity.trim_end_matches("Ki")).map(|v| v * 1024)
-    } else {
-        parse_value(quantity)
-    };
+pub fn parse_memory(quantity: &str) -> Result<Memory, AppError> {
+    if quantity.is_empty() {
+        return Ok(Memory::default());
+    }
 
-    result.map(ResourceValue)
+    quantity_to_scaled(quantity, 0)
+        .map(Memory::from_bytes)
+        .map_err(|e| AppError::ParseError(format!("Failed to parse memory quantity '{}': {}", quantity, e)))
 }
 
-pub fn format_cpu(cpu: ResourceValue) -> String {
-    let value = cpu.as_millicores();
-    if value == 0 {
+pub fn format_cpu(cpu: Cpu) -> String {
+    let nanocores = cpu.as_nanocores();
+    let magnitude = nanocores.unsigned_abs();
+    if nanocores == 0 {
         String::new()
-    } else if value >= 1000 {
-        let float_value = value as f64 / 1000.0;
+    } else if magnitude >= 1_000_000_000 {
+        let float_value = nanocores as f64 / 1_000_000_000.0;
         if float_value.fract() == 0.0 {
             format!("{}", float_value as i64) // 소수점이 필요 없으면 정수로
         } else {
             format!("{:.1}", float_value) // 소수점이 필요하면 표현
         }
+    } else if magnitude >= 1_000_000 {
+        let millicores = nanocores as f64 / 1_000_000.0;
+        if millicores.fract() == 0.0 {
+            format!("{}m", millicores as i64)
+        } else {
+            format!("{:.1}m", millicores)
+        }
+    } else if magnitude.is_multiple_of(1000) {
+        format!("{}u", nanocores / 1000) // millicore 미만은 micro/nano 단위로 표시
     } else {
-        format!("{}m", value)
+        format!("{}n", nanocores)
     }
 }
 
-pub fn format_memory(memory: ResourceValue) -> String {
+pub fn format_memory(memory: Memory) -> String {
     let bytes = memory.as_bytes();
     if bytes == 0 {
         return String::new();
     }
+    // 변화량(watch의 delta)처럼 음수일 수 있음
+    let sign = if bytes < 0 { "-" } else { "" };
+    let bytes = bytes.unsigned_abs();
 
     let (value, unit) = if bytes >= 1024 * 1024 * 1024 {
         (bytes as f64 / (1024.0 * 1024.0 * 1024.0), "Gi")
@@ -77,25 +175,94 @@ pub fn format_memory(memory: ResourceValue) -> String {
     };
 
     if value.fract() == 0.0 {
-        format!("{}{}", value as u64, unit) // 정수로 출력
+        format!("{}{}{}", sign, value as u64, unit) // 정수로 출력
+    } else {
+        format!("{}{:.1}{}", sign, value, unit) // 소수점 둘째 자리까지 출력
+    }
+}
+
+// nanocore를 10^digits로 나눈 값을 소수로 (정수 연산이라 반올림 오차 없음)
+fn format_nanocores(nanocores: i64, digits: usize) -> String {
+    let sign = if nanocores < 0 { "-" } else { "" };
+    let magnitude = nanocores.unsigned_abs();
+    let divisor = 10u64.pow(digits as u32);
+    let fraction = format!("{:0width$}", magnitude % divisor, width = digits);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        format!("{}{}", sign, magnitude / divisor)
     } else {
-        format!("{:.1}{}", value, unit) // 소수점 둘째 자리까지 출력
+        format!("{}{}.{}", sign, magnitude / divisor, fraction)
     }
 }
 
-pub fn extract_quantity<F>(
+// 단위 없는 코어 값 (CSV, Prometheus용)
+pub fn format_cores(cpu: Cpu) -> String {
+    format_nanocores(cpu.as_nanocores(), 9)
+}
+
+// millicore 값, millicore 미만은 소수로 (CSV raw: 행의 합이 TOTAL과 일치하도록 올림하지 않음)
+pub fn format_millicores(cpu: Cpu) -> String {
+    format_nanocores(cpu.as_nanocores(), 6)
+}
+
+// 천 단위 구분 기호를 넣은 개수 (예: 12,000)
+pub fn format_count(count: usize) -> String {
+    let digits = count.to_string();
+    let mut result = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            result.push(',');
+        }
+        result.push(c);
+    }
+    result
+}
+
+// total 대비 used의 비율(%) (total이 0 이하이면 계산하지 않음)
+pub fn percent(used: i64, total: i64) -> Option<f64> {
+    (total > 0).then(|| used as f64 / total as f64 * 100.0)
+}
+
+// 대소문자 구분 없이 query의 글자가 순서대로 모두 나오면 일치 (fzf처럼)
+// 일치한 글자의 byte 범위를 반환 (이어진 글자는 하나의 범위로)
+pub fn fuzzy_match(text: &str, query: &str) -> Option<Vec<Range<usize>>> {
+    let mut wanted = query.chars().flat_map(char::to_lowercase).peekable();
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for (i, c) in text.char_indices() {
+        let Some(&next) = wanted.peek() else { break };
+        if c.to_lowercase().eq(std::iter::once(next)) {
+            wanted.next();
+            let end = i + c.len_utf8();
+            match ranges.last_mut() {
+                Some(last) if last.end == i => last.end = end,
+                _ => ranges.push(i..end),
+            }
+        }
+    }
+    wanted.peek().is_none().then_some(ranges)
+}
+
+// 값이 없으면 0, 파싱에 실패하면 0으로 집계하고 경고로 기록
+pub fn extract_quantity<T, F>(
     resources: &Option<BTreeMap<String, Quantity>>,
+    section: &str,
     key: &str,
     parser: F,
-) -> ResourceValue 
+    source: &WarningSource,
+    warnings: &mut Vec<ParseWarning>,
+) -> T
 where
-    F: Fn(&str) -> Result<ResourceValue, AppError>,
+    T: Default,
+    F: Fn(&str) -> Result<T, AppError>,
 {
-    resources
-        .as_ref()
-        .and_then(|map| map.get(key))
-        .map(|quantity| parser(&quantity.0).unwrap_or(ResourceValue(0)))
-        .unwrap_or(ResourceValue(0))
+    let Some(quantity) = resources.as_ref().and_then(|map| map.get(key)) else {
+        return T::default();
+    };
+
+    parser(&quantity.0).unwrap_or_else(|e| {
+        warnings.push(source.warning(format!("{}.{}", section, key), &quantity.0, e.to_string()));
+        T::default()
+    })
 }
 
 #[cfg(test)]
@@ -104,33 +271,195 @@ mod tests {
 
     #[test]
     fn test_parse_cpu() {
-        assert_eq!(parse_cpu("1").unwrap().as_millicores(), 1000);
-        assert_eq!(parse_cpu("500m").unwrap().as_millicores(), 500);
-        assert_eq!(parse_cpu("").unwrap().as_millicores(), 0);
-        assert!(parse_cpu("invalid").is_err());
+        // (입력, nanocore)
+        let cases = [
+            ("1", 1_000_000_000),
+            ("500m", 500_000_000),
+            ("", 0),
+            ("0", 0),
+            ("0.5", 500_000_000),
+            ("1.5", 1_500_000_000),
+            (".5", 500_000_000),
+            ("+2", 2_000_000_000),
+            ("100u", 100_000),
+            ("2500000n", 2_500_000),
+            ("1n", 1),
+            ("1k", 1_000_000_000_000),
+            ("1e3", 1_000_000_000_000),
+            ("1E-3", 1_000_000),
+            ("0.1m", 100_000),
+            ("0.1n", 1),       // nanocore 미만은 올림
+            ("-1", -1_000_000_000),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(parse_cpu(input).unwrap().as_nanocores(), expected, "input: {:?}", input);
+        }
+
+        for input in ["invalid", "m", "1x", "1.2.3", "1e", "1Ki1", "--1", "1 m"] {
+            assert!(parse_cpu(input).is_err(), "input: {:?}", input);
+        }
     }
 
     #[test]
     fn test_parse_memory() {
-        assert_eq!(parse_memory("1Gi").unwrap().as_bytes(), 1024 * 1024 * 1024);
-        assert_eq!(parse_memory("1Mi").unwrap().as_bytes(), 1024 * 1024);
-        assert_eq!(parse_memory("1Ki").unwrap().as_bytes(), 1024);
-        assert_eq!(parse_memory("").unwrap().as_bytes(), 0);
-        assert!(parse_memory("invalid").is_err());
+        let cases = [
+            ("1Gi", 1024 * 1024 * 1024),
+            ("1Mi", 1024 * 1024),
+            ("1Ki", 1024),
+            ("", 0),
+            ("128974848", 128_974_848),
+            ("129e6", 129_000_000),
+            ("129M", 129_000_000),
+            ("123Mi", 123 * 1024 * 1024),
+            ("1G", 1_000_000_000),
+            ("500M", 500_000_000),
+            ("1Ti", 1 << 40),
+            ("1Pi", 1 << 50),
+            ("1Ei", 1 << 60),
+            ("1.5Gi", 1_610_612_736),
+            ("0.5Ki", 512),
+            ("1e9", 1_000_000_000),
+            ("1E", 1_000_000_000_000_000_000),
+            ("128974848000m", 128_974_848),
+            ("1500m", 2),      // 1.5 byte -> 2 byte (올림)
+            ("1k", 1000),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(parse_memory(input).unwrap().as_bytes(), expected, "input: {:?}", input);
+        }
+
+        for input in ["invalid", "Gi", "1GB", "1gi", "1e400", "10Ei", "1.5.5Gi"] {
+            assert!(parse_memory(input).is_err(), "input: {:?}", input);
+        }
     }
 
     #[test]
     fn test_format_cpu() {
-        assert_eq!(format_cpu(ResourceValue(1000)), "1");
-        assert_eq!(format_cpu(ResourceValue(500)), "500m");
-        assert_eq!(format_cpu(ResourceValue(0)), "");
+        assert_eq!(format_cpu(Cpu::from_nanocores(1_000_000_000)), "1");
+        assert_eq!(format_cpu(Cpu::from_nanocores(1_500_000_000)), "1.5");
+        assert_eq!(format_cpu(Cpu::from_nanocores(500_000_000)), "500m");
+        assert_eq!(format_cpu(Cpu::from_nanocores(2_500_000)), "2.5m");
+        assert_eq!(format_cpu(Cpu::from_nanocores(100_000)), "100u");
+        assert_eq!(format_cpu(Cpu::from_nanocores(250)), "250n");
+        assert_eq!(format_cpu(Cpu::default()), "");
     }
 
     #[test]
     fn test_format_memory() {
-        assert_eq!(format_memory(ResourceValue(1024 * 1024 * 1024)), "1Gi");
-        assert_eq!(format_memory(ResourceValue(1024 * 1024)), "1Mi");
-        assert_eq!(format_memory(ResourceValue(1024)), "1Ki");
-        assert_eq!(format_memory(ResourceValue(0)), "");
+        assert_eq!(format_memory(Memory::from_bytes(1024 * 1024 * 1024)), "1Gi");
+        assert_eq!(format_memory(Memory::from_bytes(1024 * 1024)), "1Mi");
+        assert_eq!(format_memory(Memory::from_bytes(1024)), "1Ki");
+        assert_eq!(format_memory(Memory::default()), "");
+        assert_eq!(format_memory(Memory::from_bytes(-512 * 1024 * 1024)), "-512Mi");
+    }
+
+    #[test]
+    fn test_quantity_arithmetic_saturates() {
+        let cpu = parse_cpu("100u").unwrap() + parse_cpu("2500000n").unwrap();
+        assert_eq!(cpu.as_nanocores(), 2_600_000);
+        assert_eq!((Cpu::from_nanocores(500_000_000) * 3).as_nanocores(), 1_500_000_000);
+        assert_eq!((Cpu::from_nanocores(500_000_000) - Cpu::from_nanocores(700_000_000)).as_nanocores(), -200_000_000);
+
+        let max = Memory::from_bytes(i64::MAX);
+        assert_eq!((max + Memory::from_bytes(1)).as_bytes(), i64::MAX);
+        assert_eq!((max * 2).as_bytes(), i64::MAX);
+        assert_eq!((Memory::from_bytes(i64::MIN) - Memory::from_bytes(1)).as_bytes(), i64::MIN);
+
+        let mut total = Memory::default();
+        total += Memory::from_bytes(1024);
+        total -= Memory::from_bytes(24);
+        assert_eq!(total.as_bytes(), 1000);
+        assert_eq!([Memory::from_bytes(1), Memory::from_bytes(2)].into_iter().sum::<Memory>().as_bytes(), 3);
+
+        // (nanocore, millicore): millicore 미만은 올림, 경계값에서 overflow 없음
+        let cases = [
+            (0, 0),
+            (1, 1),
+            (1_000_000, 1),
+            (1_000_001, 2),
+            (-1, 0),
+            (-1_500_000, -1),
+            (i64::MAX, 9_223_372_036_855),
+            (i64::MIN, -9_223_372_036_854),
+        ];
+        for (nanocores, millicores) in cases {
+            assert_eq!(Cpu::from_nanocores(nanocores).as_millicores(), millicores, "nanocores: {}", nanocores);
+        }
+        let delta = Cpu::from_nanocores(i64::MIN) - Cpu::from_nanocores(1);
+        assert_eq!(delta.as_millicores(), -9_223_372_036_854);
+    }
+
+    #[test]
+    fn test_format_cores() {
+        assert_eq!(format_cores(Cpu::from_nanocores(0)), "0");
+        assert_eq!(format_cores(Cpu::from_nanocores(2_000_000_000)), "2");
+        assert_eq!(format_cores(Cpu::from_nanocores(1_500_000_000)), "1.5");
+        assert_eq!(format_cores(Cpu::from_nanocores(250_000)), "0.00025");
+        assert_eq!(format_cores(Cpu::from_nanocores(-100_000_000)), "-0.1");
+        assert_eq!(format_cores(Cpu::from_nanocores(i64::MIN)), "-9223372036.854775808");
+    }
+
+    #[test]
+    fn test_format_millicores() {
+        assert_eq!(format_millicores(Cpu::from_nanocores(0)), "0");
+        assert_eq!(format_millicores(Cpu::from_nanocores(1_500_000_000)), "1500");
+        assert_eq!(format_millicores(Cpu::from_nanocores(100_000)), "0.1");
+        assert_eq!(format_millicores(Cpu::from_nanocores(2_500_001)), "2.500001");
+        assert_eq!(format_millicores(Cpu::from_nanocores(-250_000)), "-0.25");
+    }
+
+    #[test]
+    fn test_format_count() {
+        assert_eq!(format_count(0), "0");
+        assert_eq!(format_count(999), "999");
+        assert_eq!(format_count(12000), "12,000");
+        assert_eq!(format_count(1234567), "1,234,567");
+    }
+
+    #[test]
+    fn test_percent() {
+        assert_eq!(percent(50, 200), Some(25.0));
+        assert_eq!(percent(300, 200), Some(150.0));
+        assert_eq!(percent(10, 0), None);
+        assert_eq!(percent(i64::MAX, i64::MAX), Some(100.0));
+    }
+
+    #[test]
+    fn test_extract_quantity_records_warning() {
+        let resources = Some(BTreeMap::from([
+            ("cpu".to_string(), Quantity("250m".to_string())),
+            ("memory".to_string(), Quantity("1GB".to_string())),
+        ]));
+        let source = WarningSource {
+            kind: "Pod",
+            namespace: "default".to_string(),
+            name: "web".to_string(),
+            container: "app".to_string(),
+        };
+        let mut warnings = Vec::new();
+
+        let cpu = extract_quantity(&resources, "requests", "cpu", parse_cpu, &source, &mut warnings);
+        let memory = extract_quantity(&resources, "requests", "memory", parse_memory, &source, &mut warnings);
+        let missing = extract_quantity(&resources, "requests", "storage", parse_memory, &source, &mut warnings);
+
+        assert_eq!(cpu.as_nanocores(), 250_000_000);
+        assert_eq!(memory.as_bytes(), 0);
+        assert_eq!(missing.as_bytes(), 0);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].name, "web");
+        assert_eq!(warnings[0].container, "app");
+        assert_eq!(warnings[0].field, "requests.memory");
+        assert_eq!(warnings[0].value, "1GB");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_fuzzy_match() {
+        assert_eq!(fuzzy_match("kube-system", "ksys"), Some(vec![0..1, 5..8]));
+        assert_eq!(fuzzy_match("Worker-1", "wk1"), Some(vec![0..1, 3..4, 7..8]));
+        assert_eq!(fuzzy_match("api-server", "aps"), Some(vec![0..2, 4..5]));
+        assert_eq!(fuzzy_match("api-server", "xyz"), None);
+        assert_eq!(fuzzy_match("api", "apis"), None);
+        assert_eq!(fuzzy_match("데이터-api", "데api"), Some(vec![0..3, 10..13]));
+        assert_eq!(fuzzy_match("anything", ""), Some(vec![]));
+    }
+}